@@ -0,0 +1,249 @@
+//! Select whether `MksVal` stores and checks units, generate table rows of CODATA constants
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+//! `cfg(mks_checked)` is set for debug builds and for release builds
+//! without `--cfg mks_unchecked` in `RUSTFLAGS`.
+//!
+//! Physical constants are read from local copy of NIST CODATA listing
+//! <https://physics.nist.gov/cuu/Constants/Table/allascii.txt>,
+//! unit strings like `m^3 kg^-1 s^-2` are turned into powers of base units,
+//! and `codata_rows!` is written to `OUT_DIR` for `mks_table!`,
+//! with `codata_listed!` giving listed value and uncertainty for `codata::Codata2006`.
+//! Constants are looked up by quantity name exactly as NIST lists it, like `electron mag. mom.`;
+//! the local copy keeps only rows of `CONSTANTS`, the complete listing can replace it unchanged.
+//! The build fails on unit strings it can not parse, on constants missing from the listing
+//! and on truncated values like `12.566 370 614...e-7` without known exact value.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// CODATA adjustment of the listing
+const EDITION: &str = "2006";
+
+/// Local copy of the listing
+const LISTING: &str = "data/codata-2006-allascii.txt";
+
+/// Start of value, uncertainty and unit columns in listings of 2002 to 2014 adjustments
+const COLUMNS: [usize; 3] = [55, 77, 99];
+
+/// Constants taken from the listing: CODATA quantity, list name, constant name, description,
+/// and sign applied to the listed value; electron magnetic moment is kept positive as in GSL
+const CONSTANTS: [(&str, &str, &str, &str, f64); 42] = [
+    ("speed of light in vacuum",                      "SpeedOfLight",            "SPEED_OF_LIGHT",            "Speed of light",               1.0),
+    ("Newtonian constant of gravitation",             "GravitationalConstant",   "GRAVITATIONAL_CONSTANT",    "Gravitational constant",       1.0),
+    ("Planck constant",                               "PlancksConstantH",        "PLANCKS_CONSTANT_H",        "Planck's constant h",          1.0),
+    ("Planck constant over 2 pi",                     "PlancksConstantHBar",     "PLANCKS_CONSTANT_HBAR",     "Planck's constant h bar",      1.0),
+    ("standard acceleration of gravity",              "GravAccel",               "GRAV_ACCEL",                "Grav Acceleration",            1.0),
+    ("electron volt",                                 "ElectronVolt",            "ELECTRON_VOLT",             "Electron Volt",                1.0),
+    ("electron mass",                                 "MassElectron",            "MASS_ELECTRON",             "Mass of electron",             1.0),
+    ("muon mass",                                     "MassMuon",                "MASS_MUON",                 "Mass of muon",                 1.0),
+    ("proton mass",                                   "MassProton",              "MASS_PROTON",               "Mass of proton",               1.0),
+    ("neutron mass",                                  "MassNeutron",             "MASS_NEUTRON",              "Mass of neutron",              1.0),
+    ("Rydberg constant times hc in J",                "Rydberg",                 "RYDBERG",                   "Rydberg",                      1.0),
+    ("Boltzmann constant",                            "Boltzmann",               "BOLTZMANN",                 "Boltzmann",                    1.0),
+    ("molar gas constant",                            "MolarGas",                "MOLAR_GAS",                 "Molar gas",                    1.0),
+    ("molar volume of ideal gas (273.15 K, 100 kPa)", "StandardGasVolume",       "STANDARD_GAS_VOLUME",       "Standard gas volume",          1.0),
+    ("atomic mass constant",                          "UnifiedAtomicMass",       "UNIFIED_ATOMIC_MASS",       "Unified atomic mass",          1.0),
+    ("standard atmosphere",                           "StdAtmosphere",           "STD_ATMOSPHERE",            "STD atmosphere",               1.0),
+    ("Bohr radius",                                   "BohrRadius",              "BOHR_RADIUS",               "Bohr radius",                  1.0),
+    ("Stefan-Boltzmann constant",                     "StefanBolzmannConstant",  "STEFAN_BOLTZMANN_CONSTANT", "Stefan-Boltzmann constant",    1.0),
+    ("Thomson cross section",                         "ThomsonCrossSection",     "THOMSON_CROSS_SECTION",     "Thomson cross section",        1.0),
+    ("Bohr magneton",                                 "BohrMagneton",            "BOHR_MAGNETON",             "Bohr magneton",                1.0),
+    ("nuclear magneton",                              "NuclearMagneton",         "NUCLEAR_MAGNETON",          "Nuclear magneton",             1.0),
+    ("electron mag. mom.",                            "ElectronMagneticMoment",  "ELECTRON_MAGNETIC_MOMENT",  "Electron magnetic moment",    -1.0),
+    ("proton mag. mom.",                              "ProtonMagneticMoment",    "PROTON_MAGNETIC_MOMENT",    "Proton magnetic moment",       1.0),
+    ("Faraday constant",                              "Faraday",                 "FARADAY",                   "Faraday",                      1.0),
+    ("elementary charge",                             "ElectronCharge",          "ELECTRON_CHARGE",           "Electron charge",              1.0),
+    ("electric constant",                             "VacuumPermittivity",      "VACUUM_PERMITTIVITY",       "Vacuum permittivity",          1.0),
+    ("mag. constant",                                 "VacuumPermeability",      "VACUUM_PERMEABILITY",       "Vacuum permeability",          1.0),
+    ("Avogadro constant",                             "Avogadro",                "AVOGADRO",                  "Avogadro constant",            1.0),
+    ("fine-structure constant",                       "FineStructure",           "FINE_STRUCTURE",            "Fine-structure constant",      1.0),
+    ("Josephson constant",                            "Josephson",               "JOSEPHSON",                 "Josephson constant",           1.0),
+    ("von Klitzing constant",                         "VonKlitzing",             "VON_KLITZING",              "von Klitzing constant",        1.0),
+    ("mag. flux quantum",                             "MagneticFluxQuantum",     "MAGNETIC_FLUX_QUANTUM",     "Magnetic flux quantum",        1.0),
+    ("conductance quantum",                           "ConductanceQuantum",      "CONDUCTANCE_QUANTUM",       "Conductance quantum",          1.0),
+    ("Hartree energy",                                "HartreeEnergy",           "HARTREE_ENERGY",            "Hartree energy",               1.0),
+    ("Compton wavelength",                            "ComptonWavelength",       "COMPTON_WAVELENGTH",        "Compton wavelength",           1.0),
+    ("classical electron radius",                     "ClassicalElectronRadius", "CLASSICAL_ELECTRON_RADIUS", "Classical electron radius",    1.0),
+    ("Wien wavelength displacement law constant",     "WienDisplacement",        "WIEN_DISPLACEMENT",         "Wien displacement constant",   1.0),
+    ("deuteron mass",                                 "MassDeuteron",            "MASS_DEUTERON",             "Mass of deuteron",             1.0),
+    ("alpha particle mass",                           "MassAlpha",               "MASS_ALPHA",                "Mass of alpha particle",       1.0),
+    ("Planck length",                                 "PlanckLength",            "PLANCK_LENGTH",             "Planck length",                1.0),
+    ("Planck mass",                                   "PlanckMass",              "PLANCK_MASS",               "Planck mass",                  1.0),
+    ("Planck time",                                   "PlanckTime",              "PLANCK_TIME",               "Planck time",                  1.0),
+];
+
+/// Powers of m, kg, s, A, K, mol, cd
+type Dims = [i32; 7];
+
+/// Units used in the listing
+const SYMBOLS: [(&str, Dims); 20] = [
+    ("m",   [ 1,  0,  0,  0,  0,  0,  0]),
+    ("kg",  [ 0,  1,  0,  0,  0,  0,  0]),
+    ("s",   [ 0,  0,  1,  0,  0,  0,  0]),
+    ("A",   [ 0,  0,  0,  1,  0,  0,  0]),
+    ("K",   [ 0,  0,  0,  0,  1,  0,  0]),
+    ("mol", [ 0,  0,  0,  0,  0,  1,  0]),
+    ("cd",  [ 0,  0,  0,  0,  0,  0,  1]),
+    ("Hz",  [ 0,  0, -1,  0,  0,  0,  0]),
+    ("N",   [ 1,  1, -2,  0,  0,  0,  0]),
+    ("Pa",  [-1,  1, -2,  0,  0,  0,  0]),
+    ("J",   [ 2,  1, -2,  0,  0,  0,  0]),
+    ("W",   [ 2,  1, -3,  0,  0,  0,  0]),
+    ("C",   [ 0,  0,  1,  1,  0,  0,  0]),
+    ("V",   [ 2,  1, -3, -1,  0,  0,  0]),
+    ("F",   [-2, -1,  4,  2,  0,  0,  0]),
+    ("ohm", [ 2,  1, -3, -2,  0,  0,  0]),
+    ("S",   [-2, -1,  3,  2,  0,  0,  0]),
+    ("Wb",  [ 2,  1, -2, -1,  0,  0,  0]),
+    ("T",   [ 0,  1, -2, -1,  0,  0,  0]),
+    ("H",   [ 2,  1, -2, -2,  0,  0,  0]),
+];
+
+/// Row of the listing, value, uncertainty and unit as listed
+struct Row {
+    quantity: String,
+    value: String,
+    uncertainty: String,
+    unit: String,
+}
+
+/// Parse number like `6.674 28 e-11` or `8.854 187 817...e-12`
+fn parse_number(s: &str) -> Option<f64> {
+    s.replace(' ', "").replace("...", "").parse().ok()
+}
+
+/// Exact value of constant listed truncated, like `12.566 370 614...e-7`
+fn exact_value(quantity: &str) -> Option<f64> {
+    let mu0 = 4.0e-7 * std::f64::consts::PI;
+    let c = 299_792_458.0_f64;
+    match quantity {
+        "mag. constant" => Some(mu0),
+        "electric constant" => Some(1.0 / (mu0 * c * c)),
+        "characteristic impedance of vacuum" => Some(mu0 * c),
+        _ => None,
+    }
+}
+
+/// Value of row, computed in full if the listed value is truncated
+fn row_value(row: &Row) -> f64 {
+    let listed = parse_number(&row.value).filter(|_| !row.value.is_empty())
+        .unwrap_or_else(|| panic!("{}: cannot parse value of {}", LISTING, row.quantity));
+    if !row.value.contains("...") {
+        return listed;
+    }
+    let exact = exact_value(&row.quantity)
+        .unwrap_or_else(|| panic!("{}: no exact value for truncated {}", LISTING, row.quantity));
+    // listed value is exact value truncated after the last listed digit
+    let number = row.value.replace(' ', "");
+    let (mantissa, exp) = number.split_once("...").expect("truncated value");
+    let decimals = mantissa.split_once('.').map_or(0, |(_, fraction)| fraction.len() as i32);
+    let exp = exp.trim_start_matches('e').parse::<i32>().unwrap_or(0);
+    let last_digit = 10_f64.powi(exp - decimals);
+    if !(-1e-6 * last_digit..last_digit).contains(&(exact - listed)) {
+        panic!("{}: exact value {:e} of {} does not match listed {}", LISTING, exact, row.quantity, row.value);
+    }
+    exact
+}
+
+/// Parse unit like `m^3 kg^-1 s^-2` into powers of base units, empty string is scalar
+fn parse_unit(s: &str) -> Result<Dims, String> {
+    let mut dims = [0; 7];
+    for term in s.split_whitespace() {
+        let (symbol, power) = match term.split_once('^') {
+            Some((symbol, power)) => (symbol, power.parse::<i32>().map_err(|_| term.to_string())?),
+            None => (term, 1),
+        };
+        let (_, base) = SYMBOLS.iter().find(|(sym, _)| *sym == symbol).ok_or_else(|| term.to_string())?;
+        for (dim, base) in dims.iter_mut().zip(base) {
+            *dim += base * power;
+        }
+    }
+    Ok(dims)
+}
+
+/// Standard uncertainty of row, zero for `(exact)`
+fn row_uncertainty(row: &Row) -> f64 {
+    if row.uncertainty == "(exact)" {
+        return 0.0;
+    }
+    parse_number(&row.uncertainty).filter(|_| !row.uncertainty.is_empty())
+        .unwrap_or_else(|| panic!("{}: cannot parse uncertainty of {}", LISTING, row.quantity))
+}
+
+/// Parse rows below the dashed line into fixed width columns,
+/// values are parsed only for rows selected by `CONSTANTS`
+fn parse_listing(text: &str) -> Vec<Row> {
+    let lines = text.lines().skip_while(|line| !line.starts_with("---")).skip(1);
+    let column = |line: &str, start: usize, end: usize| {
+        line.get(start.min(line.len())..end.min(line.len()))
+            .unwrap_or_else(|| panic!("{}: not ASCII: {}", LISTING, line))
+            .trim().to_string()
+    };
+    lines.filter(|line| !line.trim().is_empty())
+        .map(|line| Row {
+            quantity: column(line, 0, COLUMNS[0]),
+            value: column(line, COLUMNS[0], COLUMNS[1]),
+            uncertainty: column(line, COLUMNS[1], COLUMNS[2]),
+            unit: column(line, COLUMNS[2], line.len()),
+        })
+        .collect()
+}
+
+/// Check year in header line like `2006 CODATA adjustment` if the listing has one
+fn check_edition(text: &str) {
+    let year = text.lines().find_map(|line| line.trim().strip_suffix("CODATA adjustment"));
+    if let Some(year) = year.map(str::trim).filter(|&year| year != EDITION) {
+        panic!("{}: CODATA {} listing, expected {}", LISTING, year, EDITION);
+    }
+}
+
+/// Rust source of `codata_rows!`, `codata_source!`, `codata_name!` and `codata_listed!`
+fn codata_rows(text: &str) -> String {
+    check_edition(text);
+    let rows = parse_listing(text);
+    let mut src = String::new();
+    src.push_str(&format!("// Generated by build.rs from {}\n\n", LISTING));
+    src.push_str("/// Source of constants in `codata_rows!`\n");
+    src.push_str(&format!("macro_rules! codata_source {{\n    () => {{ \"CODATA {}\" }};\n}}\n\n", EDITION));
+    src.push_str("/// Call `$callback!` with rows of CODATA constants followed by `$rows`.\n");
+    src.push_str("macro_rules! codata_rows {\n    ($callback:ident; $($rows:tt)*) => {\n        $callback! {\n");
+    let mut listed = String::new();
+    for (quantity, name, cnst, descr, sign) in CONSTANTS {
+        let row = rows.iter().find(|row| row.quantity == quantity)
+            .unwrap_or_else(|| panic!("{}: no {}", LISTING, quantity));
+        listed.push_str(&format!("    ({}) => {{ ({:e}_f64, {:e}_f64) }};\n",
+            cnst, sign * row_value(row), row_uncertainty(row)));
+        let dims = parse_unit(&row.unit)
+            .unwrap_or_else(|term| panic!("{}: cannot parse unit `{}` of {}", LISTING, term, quantity));
+        let dims: Vec<String> = dims.iter().map(|d| format!("{:2}", d)).collect();
+        src.push_str(&format!("            {}, {}, {}_UNIT, [{}], {:e}_f64, {:?}; // {}\n",
+            name, cnst, cnst, dims.join(", "), sign * row_value(row), descr, row.unit));
+    }
+    src.push_str("            $($rows)*\n        }\n    };\n}\n\n");
+    let names: Vec<String> = CONSTANTS.iter().map(|(_, name, _, _, _)| format!("Name::{}", name)).collect();
+    src.push_str("/// Pattern matching list names of constants in `codata_rows!`\n");
+    src.push_str(&format!("macro_rules! codata_name {{\n    () => {{ {} }};\n}}\n\n", names.join(" | ")));
+    src.push_str("/// Listed value and standard uncertainty of constant in `codata_rows!`\n");
+    src.push_str(&format!("macro_rules! codata_listed {{\n{}}}\n", listed));
+    src
+}
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(mks_checked)");
+    println!("cargo:rustc-check-cfg=cfg(mks_unchecked)");
+    let debug = env::var_os("CARGO_CFG_DEBUG_ASSERTIONS").is_some();
+    let unchecked = env::var_os("CARGO_CFG_MKS_UNCHECKED").is_some();
+    if debug || !unchecked {
+        println!("cargo:rustc-cfg=mks_checked");
+    }
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", LISTING);
+    let text = fs::read_to_string(LISTING).unwrap_or_else(|err| panic!("{}: {}", LISTING, err));
+    let out = Path::new(&env::var_os("OUT_DIR").unwrap()).join("codata_rows.rs");
+    fs::write(out, codata_rows(&text)).unwrap();
+}
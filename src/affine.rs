@@ -0,0 +1,272 @@
+//! Points measured from an origin, like timestamps, positions and temperatures
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+//! [`MksVal`] is a vector: two lengths or two durations can be added.
+//! [`Point`] is a position on an affine scale with [`Origin`]:
+//! point minus point is a vector, point plus vector is a point,
+//! and sum of two points does not compile.
+//!
+//! # Example
+//!
+//! ```
+//! use rustamath_mks::*;
+//! use rustamath_mks::affine::*;
+//! # #[cfg(mks_checked)] {
+//! let start: Point<UnixEpoch> = Point::new(MksVal::new(1.7e9, 1.0, TIME_UNIT));
+//! let end = start + MksVal::new(2.0, f64::HOUR, HOUR_UNIT);
+//! let duration = end - start;
+//! assert!(duration.unit == TIME_UNIT);
+//! assert_eq!(duration.val, 7200.0);
+//! # }
+//! ```
+//!
+//! ```compile_fail
+//! use rustamath_mks::*;
+//! use rustamath_mks::affine::*;
+//! let start: Point<UnixEpoch> = Point::new(MksVal::new(1.7e9, 1.0, TIME_UNIT));
+//! let _ = start + start;
+//! ```
+//!
+//! Epoch-based time scales are origins with offset from the Unix epoch:
+//!
+//! ```
+//! use rustamath_mks::*;
+//! use rustamath_mks::affine::*;
+//! # #[cfg(mks_checked)] {
+//! /// GPS epoch, 1980-01-06 00:00:00 UTC, leap seconds are not counted
+//! struct GpsEpoch;
+//! impl Origin for GpsEpoch {
+//!     const UNIT: MksUnit = TIME_UNIT;
+//!     const OFFSET: f64 = 315_964_800.0;
+//!     const NAME: &'static str = "GPS epoch";
+//! }
+//! let t: Point<GpsEpoch> = Point::new(MksVal::new(1.0, f64::WEEK, WEEK_UNIT));
+//! let unix = t.to_origin::<UnixEpoch>().unwrap();
+//! assert_eq!(unix.from_origin().val, 315_964_800.0 + f64::WEEK);
+//! assert_eq!(format!("{}", t), "604800 s from GPS epoch");
+//! # }
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops;
+use super::{MksNum, MksUnit, MksVal, UnitError, UnitOp, DISTANCE_UNIT, TIME_UNIT, KELVIN_UNIT};
+use super::value::mismatch;
+use super::temperature::AbsoluteTemperature;
+
+/// Origin of affine scale, zero point of its dimension.
+pub trait Origin {
+    /// Unit of points and vectors
+    const UNIT: MksUnit;
+    /// Position of this origin measured from the reference origin with the same unit,
+    /// in MKS units; reference origins have zero offset
+    const OFFSET: f64;
+    /// Name of origin for printing
+    const NAME: &'static str;
+}
+
+/// Reference origin of positions in space
+#[derive(Debug, Copy, Clone)]
+pub struct SpaceOrigin;
+
+impl Origin for SpaceOrigin {
+    const UNIT: MksUnit = DISTANCE_UNIT;
+    const OFFSET: f64 = 0.0;
+    const NAME: &'static str = "origin";
+}
+
+/// Reference origin of time, 1970-01-01 00:00:00 UTC
+#[derive(Debug, Copy, Clone)]
+pub struct UnixEpoch;
+
+impl Origin for UnixEpoch {
+    const UNIT: MksUnit = TIME_UNIT;
+    const OFFSET: f64 = 0.0;
+    const NAME: &'static str = "Unix epoch";
+}
+
+/// Reference origin of temperature
+#[derive(Debug, Copy, Clone)]
+pub struct AbsoluteZero;
+
+impl Origin for AbsoluteZero {
+    const UNIT: MksUnit = KELVIN_UNIT;
+    const OFFSET: f64 = 0.0;
+    const NAME: &'static str = "absolute zero";
+}
+
+/// Zero of Celsius scale, 273.15 K
+#[derive(Debug, Copy, Clone)]
+pub struct CelsiusZero;
+
+impl Origin for CelsiusZero {
+    const UNIT: MksUnit = KELVIN_UNIT;
+    const OFFSET: f64 = 273.15;
+    const NAME: &'static str = "0 °C";
+}
+
+/// Point on affine scale with origin `O`, stored as vector from the origin.
+pub struct Point<O, T = f64> {
+    val: T,
+    origin: PhantomData<O>,
+}
+
+impl<O: Origin, T: MksNum> Point<O, T> {
+    fn from_val(val: T) -> Self {
+        Point { val, origin: PhantomData }
+    }
+
+    /// Point at vector `offset` from the origin
+    ///
+    /// Unit of `offset` must be the unit of origin, otherwise the unit mismatch policy applies,
+    /// see [`Point::try_new`] and [`MismatchPolicy`](super::MismatchPolicy).
+    pub fn new(offset: MksVal<T>) -> Self {
+        Self::try_new(offset).unwrap_or_else(|err| Self::from_val(mismatch::<T>(err).val))
+    }
+
+    /// Point at vector `offset` from the origin, fail if unit is not the unit of origin
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::affine::*;
+    /// # #[cfg(mks_checked)] {
+    /// assert!(Point::<SpaceOrigin>::try_new(MksVal::new(3.0, f64::FOOT, FOOT_UNIT)).is_ok());
+    /// let err = Point::<SpaceOrigin>::try_new(MksVal::new(3.0, 1.0, TIME_UNIT)).unwrap_err();
+    /// assert_eq!(&err.to_string(), "cannot convert [s] to [m]");
+    /// # }
+    /// ```
+    pub fn try_new(offset: MksVal<T>) -> Result<Self, UnitError> {
+        #[cfg(mks_checked)]
+        if offset.unit != O::UNIT {
+            return Err(UnitError::binary(UnitOp::Convert, offset.unit, O::UNIT));
+        }
+        Ok(Self::from_val(offset.val))
+    }
+
+    /// Origin itself
+    pub fn origin() -> Self {
+        Self::from_val(T::from_f64(0.0))
+    }
+
+    /// Vector from the origin to the point
+    pub fn from_origin(&self) -> MksVal<T> {
+        MksVal::new(self.val, T::from_f64(1.0), O::UNIT)
+    }
+
+    /// Same point measured from other origin, fail if origins have different units
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::affine::*;
+    /// let t: Point<CelsiusZero> = Point::new(MksVal::new(20.0, 1.0, KELVIN_UNIT));
+    /// assert_eq!(t.to_origin::<AbsoluteZero>().unwrap().from_origin().val, 293.15);
+    /// assert!(t.to_origin::<UnixEpoch>().is_err());
+    /// ```
+    pub fn to_origin<O2: Origin>(&self) -> Result<Point<O2, T>, UnitError> {
+        if O::UNIT != O2::UNIT {
+            return Err(UnitError::binary(UnitOp::Convert, O::UNIT, O2::UNIT));
+        }
+        Ok(Point::from_val(self.val + T::from_f64(O::OFFSET - O2::OFFSET)))
+    }
+
+    /// Move point by vector, fail if unit of vector is not the unit of origin
+    pub fn try_add(&self, v: MksVal<T>) -> Result<Self, UnitError> {
+        self.from_origin().try_add(v).map(|p| Self::from_val(p.val))
+    }
+
+    /// Move point back by vector, fail if unit of vector is not the unit of origin
+    pub fn try_sub(&self, v: MksVal<T>) -> Result<Self, UnitError> {
+        self.from_origin().try_sub(v).map(|p| Self::from_val(p.val))
+    }
+}
+
+impl<O, T: Copy> Clone for Point<O, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O, T: Copy> Copy for Point<O, T> {}
+
+impl<O: Origin, T: fmt::Debug> fmt::Debug for Point<O, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Point({:?} {} from {})", self.val, O::UNIT, O::NAME)
+    }
+}
+
+impl<O: Origin, T: MksNum> fmt::Display for Point<O, T> {
+    /// Print vector from the origin same way as [`MksVal`], followed by the origin name
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.from_origin(), f)?;
+        write!(f, " from {}", O::NAME)
+    }
+}
+
+impl<O, T: PartialEq> PartialEq for Point<O, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.val == other.val
+    }
+}
+
+impl<O, T: PartialOrd> PartialOrd for Point<O, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.val.partial_cmp(&other.val)
+    }
+}
+
+impl<O: Origin, T: MksNum> ops::Sub for Point<O, T> {
+    type Output = MksVal<T>;
+
+    /// Vector between two points
+    fn sub(self, rhs: Self) -> MksVal<T> {
+        MksVal::new(self.val - rhs.val, T::from_f64(1.0), O::UNIT)
+    }
+}
+
+impl<O: Origin, T: MksNum> ops::Add<MksVal<T>> for Point<O, T> {
+    type Output = Self;
+
+    /// Move point by vector, the unit mismatch policy applies
+    /// if unit of vector is not the unit of origin.
+    fn add(self, v: MksVal<T>) -> Self {
+        Self::from_val((self.from_origin() + v).val)
+    }
+}
+
+impl<O: Origin, T: MksNum> ops::Add<Point<O, T>> for MksVal<T> {
+    type Output = Point<O, T>;
+
+    /// Move point by vector, the unit mismatch policy applies
+    /// if unit of vector is not the unit of origin.
+    fn add(self, p: Point<O, T>) -> Point<O, T> {
+        p + self
+    }
+}
+
+impl<O: Origin, T: MksNum> ops::Sub<MksVal<T>> for Point<O, T> {
+    type Output = Self;
+
+    /// Move point back by vector, the unit mismatch policy applies
+    /// if unit of vector is not the unit of origin.
+    fn sub(self, v: MksVal<T>) -> Self {
+        Self::from_val((self.from_origin() - v).val)
+    }
+}
+
+impl From<AbsoluteTemperature> for Point<AbsoluteZero> {
+    fn from(t: AbsoluteTemperature) -> Self {
+        Point::from_val(t.kelvin())
+    }
+}
+
+impl From<Point<AbsoluteZero>> for AbsoluteTemperature {
+    fn from(p: Point<AbsoluteZero>) -> Self {
+        AbsoluteTemperature::from_kelvin(p.val)
+    }
+}
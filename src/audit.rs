@@ -0,0 +1,203 @@
+//! Self-consistency audit of physical constants
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+//! Many constants are linked by physical relations, like `ħ = h / 2π` or `μ₀ ε₀ c² = 1`.
+//! [`audit`] recomputes the relations from a constant set with [`MksVal`] arithmetic
+//! and reports relative discrepancy and unit mismatch of each [`Check`],
+//! so a wrong or swapped value shows up when constant sets are switched.
+//!
+//! # Example
+//!
+//! ```
+//! use rustamath_mks::audit::*;
+//! use rustamath_mks::codata::Edition;
+//! for check in audit().iter().chain(audit_edition(Edition::Codata2018).iter()) {
+//!     assert!(check.passes(1e-6), "{}", check);
+//! }
+//! ```
+
+use std::f64::consts::PI;
+use std::fmt;
+use super::{MksVal, UnitError};
+#[cfg(mks_checked)]
+use super::UnitOp;
+use super::{KELVIN_UNIT, PASCAL_UNIT, JOULE_UNIT, ELECTRON_CHARGE_UNIT};
+use super::codata::Edition;
+use super::uncertain::Uncertain;
+use super::list::Name;
+
+/// Root of `x = 5 (1 - exp(-x))` in Wien displacement law
+const WIEN_X: f64 = 4.965_114_231_744_276;
+
+/// Relation between constants, both sides computed from a constant set
+#[derive(Debug, Copy, Clone)]
+pub struct Check {
+    /// What is checked
+    pub name: &'static str,
+    /// Relation as formula, left side is `lhs`
+    pub relation: &'static str,
+    /// Left side of the relation
+    pub lhs: MksVal,
+    /// Right side of the relation
+    pub rhs: MksVal,
+    /// Least tolerance of relative discrepancy, relative uncertainty of left side
+    /// for constants listed with few digits
+    pub tolerance: f64,
+}
+
+impl Check {
+    /// Relation between `lhs` and `rhs` without own tolerance
+    pub fn new(name: &'static str, relation: &'static str, lhs: MksVal, rhs: MksVal) -> Self {
+        Check { name, relation, lhs, rhs, tolerance: 0.0 }
+    }
+
+    /// Relation with left side listed with few digits, tolerance is its relative uncertainty
+    fn listed(name: &'static str, relation: &'static str, lhs: Uncertain, rhs: MksVal) -> Self {
+        Check { tolerance: lhs.relative_sigma(), ..Check::new(name, relation, lhs.value, rhs) }
+    }
+
+    /// Relative discrepancy `|lhs / rhs - 1|`
+    pub fn discrepancy(&self) -> f64 {
+        (self.lhs.val / self.rhs.val - 1.0).abs()
+    }
+
+    /// Error if sides have different units
+    ///
+    /// Unchecked builds do not store units and never fail.
+    pub fn unit_mismatch(&self) -> Option<UnitError> {
+        #[cfg(mks_checked)]
+        if self.lhs.unit != self.rhs.unit {
+            return Some(UnitError::binary(UnitOp::Convert, self.lhs.unit, self.rhs.unit));
+        }
+        None
+    }
+
+    /// Units match and relative discrepancy is within `tolerance`,
+    /// or within own [`Check::tolerance`] if that is larger
+    pub fn passes(&self, tolerance: f64) -> bool {
+        self.unit_mismatch().is_none() && self.discrepancy() <= tolerance.max(self.tolerance)
+    }
+}
+
+impl fmt::Display for Check {
+    /// Print relation followed by discrepancy or unit mismatch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::audit::*;
+    /// let check = audit().into_iter().find(|check| check.relation == "ħ = h / 2π").unwrap();
+    /// assert!(check.to_string().starts_with("reduced Planck constant, ħ = h / 2π: relative discrepancy"));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}, {}: ", self.name, self.relation)?;
+        match self.unit_mismatch() {
+            Some(err) => write!(f, "{}", err),
+            None => write!(f, "relative discrepancy {:.1e}", self.discrepancy()),
+        }
+    }
+}
+
+/// Check relations between constants of [`Mks`](super::Mks) for `f64`,
+/// with uncertainties of CODATA 2006
+pub fn audit() -> Vec<Check> {
+    audit_with(|name| {
+        let sigma = Edition::Codata2006.uncertainty(name).expect("CODATA constant");
+        Uncertain::new(MksVal::new(1.0, name.info().factor, name.info().unit), sigma)
+    })
+}
+
+/// Check relations between constants of CODATA edition
+pub fn audit_edition(edition: Edition) -> Vec<Check> {
+    audit_with(|name| edition.uncertain(name).expect("CODATA constant"))
+}
+
+/// Check relations between constants given by `constant`, like constants of own set
+///
+/// Uncertainties set the tolerance of relations of Planck units, which are listed with few digits.
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// use rustamath_mks::audit::*;
+/// use rustamath_mks::list::Name;
+/// # #[cfg(mks_checked)] {
+/// let swapped = audit_with(|name| {
+///     let name = if name == Name::VacuumPermeability { Name::VacuumPermittivity } else { name };
+///     MksVal::new(1.0, name.info().factor, name.info().unit).into()
+/// });
+/// let failed = swapped.iter().find(|check| !check.passes(1e-6)).unwrap();
+/// assert_eq!(failed.relation, "μ₀ ε₀ c² = 1");
+/// assert!(failed.unit_mismatch().is_some());
+/// # }
+/// ```
+pub fn audit_with<F: Fn(Name) -> Uncertain>(uncertain: F) -> Vec<Check> {
+    let constant = |name| uncertain(name).value;
+    let c = constant(Name::SpeedOfLight);
+    let h = constant(Name::PlancksConstantH);
+    let hbar = constant(Name::PlancksConstantHBar);
+    let e = constant(Name::ElectronCharge);
+    let k = constant(Name::Boltzmann);
+    let me = constant(Name::MassElectron);
+    let eps0 = constant(Name::VacuumPermittivity);
+    let na = constant(Name::Avogadro);
+    let big_g = constant(Name::GravitationalConstant);
+    let n = MksVal::new_scalar;
+    let volt = MksVal::new(1.0, 1.0, JOULE_UNIT / ELECTRON_CHARGE_UNIT);
+    let electron_radius = e.pow(2) / (n(4.0 * PI) * eps0 * me * c.pow(2));
+    vec![
+        Check::new("reduced Planck constant", "ħ = h / 2π",
+            hbar, h / n(2.0 * PI)),
+        Check::new("electron volt", "eV = e × 1 V",
+            constant(Name::ElectronVolt), e * volt),
+        Check::new("Faraday constant", "F = e NA",
+            constant(Name::Faraday), e * na),
+        Check::new("molar gas constant", "R = k NA",
+            constant(Name::MolarGas), k * na),
+        Check::new("fine-structure constant", "α = e² / (4π ε₀ ħ c)",
+            constant(Name::FineStructure), e.pow(2) / (n(4.0 * PI) * eps0 * hbar * c)),
+        Check::new("Josephson constant", "KJ = 2e / h",
+            constant(Name::Josephson), n(2.0) * e / h),
+        Check::new("von Klitzing constant", "RK = h / e²",
+            constant(Name::VonKlitzing), h / e.pow(2)),
+        Check::new("magnetic flux quantum", "Φ₀ = h / 2e",
+            constant(Name::MagneticFluxQuantum), h / (n(2.0) * e)),
+        Check::new("conductance quantum", "G₀ = 2e² / h",
+            constant(Name::ConductanceQuantum), n(2.0) * e.pow(2) / h),
+        Check::new("Stefan-Boltzmann constant", "σ = 2π⁵ k⁴ / (15 h³ c²)",
+            constant(Name::StefanBolzmannConstant),
+            n(2.0 * PI.powi(5) / 15.0) * k.pow(4) / (h.pow(3) * c.pow(2))),
+        Check::new("vacuum permeability and permittivity", "μ₀ ε₀ c² = 1",
+            constant(Name::VacuumPermeability) * eps0 * c.pow(2), n(1.0)),
+        Check::new("Rydberg energy", "Ry = mₑ e⁴ / (8 ε₀² h²)",
+            constant(Name::Rydberg), me * e.pow(4) / (n(8.0) * eps0.pow(2) * h.pow(2))),
+        Check::new("Hartree energy", "Eh = 2 Ry",
+            constant(Name::HartreeEnergy), n(2.0) * constant(Name::Rydberg)),
+        Check::new("Compton wavelength", "λC = h / (mₑ c)",
+            constant(Name::ComptonWavelength), h / (me * c)),
+        Check::new("classical electron radius", "rₑ = e² / (4π ε₀ mₑ c²)",
+            constant(Name::ClassicalElectronRadius), electron_radius),
+        Check::new("Bohr radius", "a₀ = 4π ε₀ ħ² / (mₑ e²)",
+            constant(Name::BohrRadius), n(4.0 * PI) * eps0 * hbar.pow(2) / (me * e.pow(2))),
+        Check::new("Bohr magneton", "μB = e ħ / 2mₑ",
+            constant(Name::BohrMagneton), e * hbar / (n(2.0) * me)),
+        Check::new("nuclear magneton", "μN = e ħ / 2mₚ",
+            constant(Name::NuclearMagneton), e * hbar / (n(2.0) * constant(Name::MassProton))),
+        Check::new("Thomson cross section", "σₑ = 8π rₑ² / 3, rₑ = e² / (4π ε₀ mₑ c²)",
+            constant(Name::ThomsonCrossSection), n(8.0 * PI / 3.0) * electron_radius.pow(2)),
+        Check::new("Wien displacement constant", "b = h c / (x k), x = 5 (1 - exp(-x))",
+            constant(Name::WienDisplacement), h * c / (n(WIEN_X) * k)),
+        Check::listed("Planck length", "lP² = ħ G / c³",
+            uncertain(Name::PlanckLength).pow(2), hbar * big_g / c.pow(3)),
+        Check::listed("Planck mass", "mP² = ħ c / G",
+            uncertain(Name::PlanckMass).pow(2), hbar * c / big_g),
+        Check::listed("Planck time", "tP = lP / c",
+            uncertain(Name::PlanckTime), constant(Name::PlanckLength) / c),
+        Check::new("molar volume of ideal gas", "Vm = R T / p, T = 273.15 K, p = 100 kPa",
+            constant(Name::StandardGasVolume),
+            constant(Name::MolarGas) * MksVal::new(273.15, 1.0, KELVIN_UNIT) / MksVal::new(1.0e5, 1.0, PASCAL_UNIT)),
+    ]
+}
@@ -0,0 +1,260 @@
+//! Physical constants of selected CODATA edition
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+//! [`Mks`](super::Mks) for `f64` and [`Codata2006`] have CODATA 2006 constants, read at build time
+//! from NIST listing in `data/` together with their uncertainties.
+//! [`Codata`] gives published values of one edition, each set records its [`Edition`],
+//! so results can be reproduced against a named reference.
+//! Measured constants have standard uncertainties, see [`Edition::uncertain`].
+//! Electron magnetic moment is the magnitude, as in GSL; CODATA lists it negative.
+//! Since CODATA 2018 Planck constant, elementary charge and Boltzmann constant are exact,
+//! as defined by SI 2019.
+//!
+//! # Example
+//!
+//! ```
+//! use rustamath_mks::codata::*;
+//! fn thermal_energy<C: Codata>(kelvin: f64) -> f64 { C::BOLTZMANN * kelvin }
+//! assert_eq!(thermal_energy::<Codata2018>(1.0), 1.380649e-23);
+//! assert_eq!(thermal_energy::<Codata2006>(1.0), 1.3806504e-23);
+//! assert_eq!(Codata2022::EDITION.to_string(), "CODATA 2022");
+//! ```
+
+use std::fmt;
+use super::MksVal;
+use super::uncertain::Uncertain;
+use super::list::Name;
+
+/// CODATA recommended values of the fundamental physical constants, by year of adjustment
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Edition {
+    /// CODATA 2006, Rev. Mod. Phys. 80, 633 (2008)
+    Codata2006,
+    /// CODATA 2014, Rev. Mod. Phys. 88, 035009 (2016)
+    Codata2014,
+    /// CODATA 2018, Rev. Mod. Phys. 93, 025010 (2021)
+    Codata2018,
+    /// CODATA 2022, Rev. Mod. Phys. 97, 025002 (2025)
+    Codata2022,
+}
+
+/// CODATA 2006 constants
+#[derive(Debug, Copy, Clone)]
+pub struct Codata2006;
+
+/// CODATA 2014 constants
+#[derive(Debug, Copy, Clone)]
+pub struct Codata2014;
+
+/// CODATA 2018 constants
+#[derive(Debug, Copy, Clone)]
+pub struct Codata2018;
+
+/// CODATA 2022 constants
+#[derive(Debug, Copy, Clone)]
+pub struct Codata2022;
+
+/// Expand rows `Name, CNST, [value, uncertainty]` of 2014, 2018 and 2022 editions
+/// into [`Codata`], [`Edition::value`] and [`Edition::uncertainty`];
+/// 2006 values and uncertainties are `codata_listed!`, generated by `build.rs` from the listing.
+macro_rules! codata_table {
+    ($($name:ident, $cnst:ident, [$v2014:expr, $u2014:expr],
+        [$v2018:expr, $u2018:expr], [$v2022:expr, $u2022:expr];)*) => {
+        /// Constants of one CODATA edition, in MKS units, named as in [`Mks`](super::Mks).
+        pub trait Codata {
+            /// Edition of the constants
+            const EDITION: Edition;
+            $(
+                #[doc = concat!("Value of [`Name::", stringify!($name), "`]")]
+                const $cnst: f64;
+            )*
+        }
+
+        impl Codata for Codata2006 {
+            const EDITION: Edition = Edition::Codata2006;
+            $(const $cnst: f64 = codata_listed!($cnst).0;)*
+        }
+
+        impl Codata for Codata2014 {
+            const EDITION: Edition = Edition::Codata2014;
+            $(const $cnst: f64 = $v2014;)*
+        }
+
+        impl Codata for Codata2018 {
+            const EDITION: Edition = Edition::Codata2018;
+            $(const $cnst: f64 = $v2018;)*
+        }
+
+        impl Codata for Codata2022 {
+            const EDITION: Edition = Edition::Codata2022;
+            $(const $cnst: f64 = $v2022;)*
+        }
+
+        impl Edition {
+            /// Value of constant in this edition, `None` if `name` is not a CODATA constant
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use rustamath_mks::codata::*;
+            /// use rustamath_mks::list::Name;
+            /// assert_eq!(Edition::Codata2014.value(Name::GravitationalConstant), Some(6.67408e-11));
+            /// assert_eq!(Edition::Codata2014.value(Name::Foot), None);
+            /// ```
+            pub fn value(&self, name: Name) -> Option<f64> {
+                match name {
+                    $(
+                        Name::$name => Some(match self {
+                            Edition::Codata2006 => Codata2006::$cnst,
+                            Edition::Codata2014 => Codata2014::$cnst,
+                            Edition::Codata2018 => Codata2018::$cnst,
+                            Edition::Codata2022 => Codata2022::$cnst,
+                        }),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Standard uncertainty of constant in this edition, zero for exact constants,
+            /// `None` if `name` is not a CODATA constant
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use rustamath_mks::codata::*;
+            /// use rustamath_mks::list::Name;
+            /// assert_eq!(Edition::Codata2018.uncertainty(Name::GravitationalConstant), Some(1.5e-15));
+            /// assert_eq!(Edition::Codata2018.uncertainty(Name::Boltzmann), Some(0.0));
+            /// assert_eq!(Edition::Codata2014.uncertainty(Name::Boltzmann), Some(7.9e-30));
+            /// ```
+            pub fn uncertainty(&self, name: Name) -> Option<f64> {
+                match name {
+                    $(
+                        Name::$name => Some(match self {
+                            Edition::Codata2006 => codata_listed!($cnst).1,
+                            Edition::Codata2014 => $u2014,
+                            Edition::Codata2018 => $u2018,
+                            Edition::Codata2022 => $u2022,
+                        }),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+/// Magnetic constant before SI 2019, `4 π 1e-7`
+const MU0_EXACT: f64 = 4.0e-7 * std::f64::consts::PI;
+
+/// Electric constant before SI 2019, `1 / (μ₀ c²)`
+const EPS0_EXACT: f64 = 1.0 / (MU0_EXACT * 299_792_458.0 * 299_792_458.0);
+
+codata_table! {
+    SpeedOfLight,           SPEED_OF_LIGHT,            [2.99792458e8, 0.0],            [2.99792458e8, 0.0],            [2.99792458e8, 0.0];
+    GravitationalConstant,  GRAVITATIONAL_CONSTANT,    [6.67408e-11, 3.1e-15],         [6.67430e-11, 1.5e-15],         [6.67430e-11, 1.5e-15];
+    PlancksConstantH,       PLANCKS_CONSTANT_H,        [6.626070040e-34, 8.1e-42],     [6.62607015e-34, 0.0],          [6.62607015e-34, 0.0];
+    PlancksConstantHBar,    PLANCKS_CONSTANT_HBAR,     [1.054571800e-34, 1.3e-42],     [1.054571817e-34, 0.0],         [1.054571817e-34, 0.0];
+    ElectronVolt,           ELECTRON_VOLT,             [1.6021766208e-19, 9.8e-28],    [1.602176634e-19, 0.0],         [1.602176634e-19, 0.0];
+    MassElectron,           MASS_ELECTRON,             [9.10938356e-31, 1.1e-38],      [9.1093837015e-31, 2.8e-40],    [9.1093837139e-31, 2.8e-40];
+    MassMuon,               MASS_MUON,                 [1.883531594e-28, 4.8e-36],     [1.883531627e-28, 4.2e-36],     [1.883531627e-28, 4.2e-36];
+    MassProton,             MASS_PROTON,               [1.672621898e-27, 2.1e-35],     [1.67262192369e-27, 5.1e-37],   [1.67262192595e-27, 5.2e-37];
+    MassNeutron,            MASS_NEUTRON,              [1.674927471e-27, 2.1e-35],     [1.67492749804e-27, 9.5e-37],   [1.67492750056e-27, 8.5e-37];
+    Rydberg,                RYDBERG,                   [2.179872325e-18, 2.7e-26],     [2.1798723611035e-18, 4.2e-30], [2.1798723611030e-18, 2.4e-30];
+    Boltzmann,              BOLTZMANN,                 [1.38064852e-23, 7.9e-30],      [1.380649e-23, 0.0],            [1.380649e-23, 0.0];
+    MolarGas,               MOLAR_GAS,                 [8.3144598, 4.8e-6],            [8.314462618, 0.0],             [8.314462618, 0.0];
+    StandardGasVolume,      STANDARD_GAS_VOLUME,       [2.2710947e-2, 1.3e-8],         [2.271095464e-2, 0.0],          [2.271095464e-2, 0.0];
+    UnifiedAtomicMass,      UNIFIED_ATOMIC_MASS,       [1.660539040e-27, 2.0e-35],     [1.66053906660e-27, 5.0e-37],   [1.66053906892e-27, 5.2e-37];
+    BohrRadius,             BOHR_RADIUS,               [5.2917721067e-11, 1.2e-20],    [5.29177210903e-11, 8.0e-21],   [5.29177210544e-11, 8.2e-21];
+    StefanBolzmannConstant, STEFAN_BOLTZMANN_CONSTANT, [5.670367e-8, 1.3e-13],         [5.670374419e-8, 0.0],          [5.670374419e-8, 0.0];
+    ThomsonCrossSection,    THOMSON_CROSS_SECTION,     [6.6524587158e-29, 9.1e-38],    [6.6524587321e-29, 6.0e-38],    [6.6524587051e-29, 6.2e-38];
+    BohrMagneton,           BOHR_MAGNETON,             [9.274009994e-24, 5.7e-32],     [9.2740100783e-24, 2.8e-33],    [9.2740100657e-24, 2.9e-33];
+    NuclearMagneton,        NUCLEAR_MAGNETON,          [5.050783699e-27, 3.1e-35],     [5.0507837461e-27, 1.5e-36],    [5.0507837393e-27, 1.6e-36];
+    ElectronMagneticMoment, ELECTRON_MAGNETIC_MOMENT,  [9.284764620e-24, 5.7e-32],     [9.2847647043e-24, 2.8e-33],    [9.2847646917e-24, 2.9e-33];
+    ProtonMagneticMoment,   PROTON_MAGNETIC_MOMENT,    [1.4106067873e-26, 9.7e-35],    [1.41060679736e-26, 6.0e-36],   [1.41060679545e-26, 6.0e-36];
+    Faraday,                FARADAY,                   [9.648533289e4, 5.9e-4],        [9.648533212e4, 0.0],           [9.648533212e4, 0.0];
+    ElectronCharge,         ELECTRON_CHARGE,           [1.6021766208e-19, 9.8e-28],    [1.602176634e-19, 0.0],         [1.602176634e-19, 0.0];
+    VacuumPermittivity,     VACUUM_PERMITTIVITY,       [EPS0_EXACT, 0.0],              [8.8541878128e-12, 1.3e-21],    [8.8541878188e-12, 1.4e-21];
+    VacuumPermeability,     VACUUM_PERMEABILITY,       [MU0_EXACT, 0.0],               [1.25663706212e-6, 1.9e-16],    [1.25663706127e-6, 2.0e-16];
+    Avogadro,               AVOGADRO,                  [6.022140857e23, 7.4e15],       [6.02214076e23, 0.0],           [6.02214076e23, 0.0];
+    FineStructure,          FINE_STRUCTURE,            [7.2973525664e-3, 1.7e-12],     [7.2973525693e-3, 1.1e-12],     [7.2973525643e-3, 1.1e-12];
+    Josephson,              JOSEPHSON,                 [4.835978525e14, 3.0e6],        [4.835978484e14, 0.0],          [4.835978484e14, 0.0];
+    VonKlitzing,            VON_KLITZING,              [2.58128074555e4, 5.9e-6],      [2.581280745e4, 0.0],           [2.581280745e4, 0.0];
+    MagneticFluxQuantum,    MAGNETIC_FLUX_QUANTUM,     [2.067833831e-15, 1.3e-23],     [2.067833848e-15, 0.0],         [2.067833848e-15, 0.0];
+    ConductanceQuantum,     CONDUCTANCE_QUANTUM,       [7.7480917310e-5, 1.8e-14],     [7.748091729e-5, 0.0],          [7.748091729e-5, 0.0];
+    HartreeEnergy,          HARTREE_ENERGY,            [4.359744650e-18, 5.4e-26],     [4.3597447222071e-18, 8.5e-30], [4.3597447222060e-18, 4.8e-30];
+    ComptonWavelength,      COMPTON_WAVELENGTH,        [2.4263102367e-12, 1.1e-21],    [2.42631023867e-12, 7.3e-22],   [2.42631023538e-12, 7.6e-22];
+    ClassicalElectronRadius, CLASSICAL_ELECTRON_RADIUS, [2.8179403227e-15, 1.9e-24],    [2.8179403262e-15, 1.3e-24],    [2.8179403205e-15, 1.3e-24];
+    WienDisplacement,       WIEN_DISPLACEMENT,         [2.8977729e-3, 1.7e-9],         [2.897771955e-3, 0.0],          [2.897771955e-3, 0.0];
+    MassDeuteron,           MASS_DEUTERON,             [3.343583719e-27, 4.1e-35],     [3.3435837724e-27, 1.0e-36],    [3.3435837768e-27, 1.0e-36];
+    MassAlpha,              MASS_ALPHA,                [6.644657230e-27, 8.2e-35],     [6.6446573357e-27, 2.0e-36],    [6.6446573450e-27, 2.1e-36];
+    PlanckLength,           PLANCK_LENGTH,             [1.616229e-35, 3.8e-40],        [1.616255e-35, 1.8e-40],        [1.616255e-35, 1.8e-40];
+    PlanckMass,             PLANCK_MASS,               [2.176470e-8, 5.1e-13],         [2.176434e-8, 2.4e-13],         [2.176434e-8, 2.4e-13];
+    PlanckTime,             PLANCK_TIME,               [5.39116e-44, 1.3e-48],         [5.391247e-44, 6.0e-50],        [5.391247e-44, 6.0e-50];
+}
+
+impl Edition {
+    /// All editions, oldest first
+    pub const ALL: [Edition; 4] = [Edition::Codata2006, Edition::Codata2014, Edition::Codata2018, Edition::Codata2022];
+
+    /// Latest edition
+    pub const LATEST: Edition = Edition::Codata2022;
+
+    /// Year of the adjustment
+    pub fn year(&self) -> u16 {
+        match self {
+            Edition::Codata2006 => 2006,
+            Edition::Codata2014 => 2014,
+            Edition::Codata2018 => 2018,
+            Edition::Codata2022 => 2022,
+        }
+    }
+
+    /// Edition of the year, `None` if there is no such edition
+    pub fn from_year(year: u16) -> Option<Edition> {
+        Edition::ALL.into_iter().find(|edition| edition.year() == year)
+    }
+
+    /// Constant of this edition with its unit attached, `None` if `name` is not a CODATA constant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::codata::*;
+    /// # #[cfg(mks_checked)] {
+    /// let h = Edition::Codata2018.val(list::Name::PlancksConstantH).unwrap();
+    /// assert!(h.unit == PLANCKS_CONSTANT_H_UNIT);
+    /// assert_eq!(h.val, 6.62607015e-34);
+    /// # }
+    /// ```
+    pub fn val(&self, name: Name) -> Option<MksVal> {
+        self.value(name).map(|v| MksVal::new(v, 1.0, name.info().unit))
+    }
+
+    /// Constant of this edition with its unit and standard uncertainty,
+    /// `None` if `name` is not a CODATA constant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::codata::*;
+    /// use rustamath_mks::list::Name;
+    /// # #[cfg(mks_checked)] {
+    /// let g = Edition::Codata2022.uncertain(Name::GravitationalConstant).unwrap();
+    /// assert_eq!(g.to_string(), "6.67430(15)e-11 m^3 / kg s^2");
+    /// assert!(Edition::Codata2022.uncertain(Name::SpeedOfLight).unwrap().is_exact());
+    /// # }
+    /// ```
+    pub fn uncertain(&self, name: Name) -> Option<Uncertain> {
+        Some(Uncertain::new(self.val(name)?, self.uncertainty(name)?))
+    }
+}
+
+impl fmt::Display for Edition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CODATA {}", self.year())
+    }
+}
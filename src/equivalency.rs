@@ -0,0 +1,168 @@
+//! Conversions between different dimensions through physical laws
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+//! Outside of equivalency context values with different units can not be converted.
+//! [`Equivalencies`] is an opt-in list of rules like `E = h ν` that relate
+//! two units with constants from [`Mks`], rules are chained if needed,
+//! for example wavelength to frequency to photon energy.
+//!
+//! # Example
+//!
+//! ```
+//! use rustamath_mks::*;
+//! use rustamath_mks::equivalency::*;
+//! # #[cfg(mks_checked)] {
+//! let green: MksVal = "500 nm".parse().unwrap();
+//! let energy = green.convert_with(ELECTRON_VOLT_UNIT, &SPECTRAL).unwrap();
+//! assert!((energy.in_unit(list::Name::ElectronVolt).unwrap() - 2.48).abs() < 0.01);
+//! assert!(green.convert_with(ELECTRON_VOLT_UNIT, &MASS_ENERGY).is_err());
+//! # }
+//! ```
+
+use super::{Mks, MksUnit, UnitError, UnitOp};
+#[cfg(mks_checked)]
+use super::{MksNum, MksVal};
+use super::{DISTANCE_UNIT, HERTZ_UNIT, JOULE_UNIT, KELVIN_UNIT, KILOGRAM_UNIT, SCALAR_UNIT};
+
+/// Rule of conversion between two units, `backward` is inverse of `forward`.
+#[derive(Debug, Copy, Clone)]
+pub struct Equivalence {
+    /// Unit of input of `forward`
+    pub from: MksUnit,
+    /// Unit of output of `forward`
+    pub to: MksUnit,
+    /// Convert value in `from` unit to `to` unit, values are in MKS
+    pub forward: fn(f64) -> f64,
+    /// Convert value in `to` unit to `from` unit, values are in MKS
+    pub backward: fn(f64) -> f64,
+}
+
+/// Named list of conversion rules.
+#[derive(Debug, Copy, Clone)]
+pub struct Equivalencies {
+    /// Name of the context
+    pub name: &'static str,
+    /// Conversion rules
+    pub rules: &'static [Equivalence],
+}
+
+/// Radians in one arcsecond
+const ARCSEC: f64 = std::f64::consts::PI / 648_000.0;
+
+/// Wavelength `λ`, frequency `ν = c / λ`, photon energy `E = h ν` and wavenumber `1 / λ`.
+pub const SPECTRAL: Equivalencies = Equivalencies {
+    name: "spectral",
+    rules: &[
+        Equivalence {
+            from: DISTANCE_UNIT, to: HERTZ_UNIT,
+            forward: |wavelength| f64::SPEED_OF_LIGHT / wavelength,
+            backward: |frequency| f64::SPEED_OF_LIGHT / frequency,
+        },
+        Equivalence {
+            from: HERTZ_UNIT, to: JOULE_UNIT,
+            forward: |frequency| f64::PLANCKS_CONSTANT_H * frequency,
+            backward: |energy| energy / f64::PLANCKS_CONSTANT_H,
+        },
+        Equivalence {
+            from: DISTANCE_UNIT, to: MksUnit { m: -1, k: 0, s: 0, a: 0, kel: 0, mol: 0, cd: 0, den: 1 },
+            forward: |wavelength| 1.0 / wavelength,
+            backward: |wavenumber| 1.0 / wavenumber,
+        },
+    ],
+};
+
+/// Rest mass and energy, `E = m c^2`.
+pub const MASS_ENERGY: Equivalencies = Equivalencies {
+    name: "mass-energy",
+    rules: &[
+        Equivalence {
+            from: KILOGRAM_UNIT, to: JOULE_UNIT,
+            forward: |mass| mass * f64::SPEED_OF_LIGHT * f64::SPEED_OF_LIGHT,
+            backward: |energy| energy / (f64::SPEED_OF_LIGHT * f64::SPEED_OF_LIGHT),
+        },
+    ],
+};
+
+/// Temperature and thermal energy, `E = k T`.
+pub const TEMPERATURE_ENERGY: Equivalencies = Equivalencies {
+    name: "temperature-energy",
+    rules: &[
+        Equivalence {
+            from: KELVIN_UNIT, to: JOULE_UNIT,
+            forward: |temperature| temperature * f64::BOLTZMANN,
+            backward: |energy| energy / f64::BOLTZMANN,
+        },
+    ],
+};
+
+/// Parallax angle in radians and distance, 1 arcsecond is 1 parsec.
+pub const PARALLAX: Equivalencies = Equivalencies {
+    name: "parallax",
+    rules: &[
+        Equivalence {
+            from: SCALAR_UNIT, to: DISTANCE_UNIT,
+            forward: |angle| f64::PARSEC * ARCSEC / angle,
+            backward: |distance| f64::PARSEC * ARCSEC / distance,
+        },
+    ],
+};
+
+impl Equivalencies {
+    /// Convert value in MKS from unit `from` to unit `to`, fail if no chain of rules connects them
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::equivalency::*;
+    /// let distance = PARALLAX.convert(0.5 * std::f64::consts::PI / 648_000.0, SCALAR_UNIT, DISTANCE_UNIT).unwrap();
+    /// assert!((distance / f64::PARSEC - 2.0).abs() < 1e-12);
+    /// let err = PARALLAX.convert(1.0, TIME_UNIT, DISTANCE_UNIT).unwrap_err();
+    /// assert_eq!(&err.to_string(), "cannot convert [s] to [m]");
+    /// ```
+    pub fn convert(&self, val: f64, from: MksUnit, to: MksUnit) -> Result<f64, UnitError> {
+        self.search(val, from, to, self.rules.len())
+            .ok_or_else(|| UnitError::binary(UnitOp::Convert, from, to))
+    }
+
+    /// Depth-first search of rule chain of at most `depth` rules
+    fn search(&self, val: f64, from: MksUnit, to: MksUnit, depth: usize) -> Option<f64> {
+        if from == to { return Some(val); }
+        if depth == 0 { return None; }
+        self.rules.iter().find_map(|rule| {
+            if rule.from == from {
+                self.search((rule.forward)(val), rule.to, to, depth - 1)
+            }
+            else if rule.to == from {
+                self.search((rule.backward)(val), rule.from, to, depth - 1)
+            }
+            else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(mks_checked)]
+impl<T: MksNum> MksVal<T> {
+    /// Convert to other unit using equivalency rules, fail if no chain of rules connects the units
+    ///
+    /// Not available in `mks_unchecked` release builds that do not store units,
+    /// use [`Equivalencies::convert`] there.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::equivalency::*;
+    /// let electron = MksVal::new(1.0, f64::MASS_ELECTRON, MASS_ELECTRON_UNIT);
+    /// let rest_energy = electron.convert_with(JOULE_UNIT, &MASS_ENERGY).unwrap();
+    /// assert!((rest_energy.in_unit(list::Name::ElectronVolt).unwrap() / 1.0e6 - 0.511).abs() < 1e-3);
+    /// ```
+    pub fn convert_with(&self, unit: MksUnit, equivalencies: &Equivalencies) -> Result<MksVal<T>, UnitError> {
+        let val = equivalencies.convert(self.val.to_f64(), self.unit, unit)?;
+        Ok(MksVal::new(T::from_f64(val), T::from_f64(1.0), unit))
+    }
+}
@@ -0,0 +1,133 @@
+//! Errors of operations on units of measurement
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+
+use std::fmt;
+use super::MksUnit;
+
+/// Operation on MKS values that can fail because of units.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum UnitOp {
+    /// Addition
+    Add,
+    /// Subtraction
+    Sub,
+    /// Square root
+    Sqrt,
+    /// Cubic root
+    Cbrt,
+    /// Raise to integer power
+    Pow(i8),
+    /// Integer root
+    Root(i8),
+    /// Raise to real power
+    Powf(f64),
+    /// Raise to rational power
+    PowRatio(i8, i8),
+    /// Convert to other unit
+    Convert,
+}
+
+impl fmt::Display for UnitOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UnitOp::Add  => write!(f, "add"),
+            UnitOp::Sub  => write!(f, "subtract"),
+            UnitOp::Sqrt => write!(f, "take square root of"),
+            UnitOp::Cbrt => write!(f, "take cubic root of"),
+            UnitOp::Pow(n) => write!(f, "raise to power {}", n),
+            UnitOp::Root(n) => write!(f, "take root {} of", n),
+            UnitOp::Powf(x) => write!(f, "raise to power {}", x),
+            UnitOp::PowRatio(n, d) => write!(f, "raise to power {}/{}", n, d),
+            UnitOp::Convert => write!(f, "convert"),
+        }
+    }
+}
+
+/// Error of operation on values with incompatible units.
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// # #[cfg(mks_checked)] {
+/// let len = MksVal::new(6.0, f64::FOOT, FOOT_UNIT);
+/// let mass = MksVal::new(2.0, f64::POUND_MASS, POUND_MASS_UNIT);
+/// let err = len.try_add(mass).unwrap_err();
+/// assert_eq!(err.op, UnitOp::Add);
+/// assert_eq!(&err.to_string(), "cannot add [m] and [kg]");
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UnitError {
+    /// Failed operation
+    pub op: UnitOp,
+    /// Unit of left (or only) operand
+    pub lhs: MksUnit,
+    /// Unit of right operand, `None` for unary operations
+    pub rhs: Option<MksUnit>,
+}
+
+impl UnitError {
+    /// Error of binary operation
+    pub fn binary(op: UnitOp, lhs: MksUnit, rhs: MksUnit) -> UnitError {
+        UnitError { op, lhs, rhs: Some(rhs) }
+    }
+
+    /// Error of unary operation
+    pub fn unary(op: UnitOp, unit: MksUnit) -> UnitError {
+        UnitError { op, lhs: unit, rhs: None }
+    }
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.rhs {
+            Some(rhs) if self.op == UnitOp::Convert => write!(f, "cannot {} {} to {}", self.op, self.lhs, rhs),
+            Some(rhs) => write!(f, "cannot {} {} and {}", self.op, self.lhs, rhs),
+            None => write!(f, "cannot {} {}", self.op, self.lhs),
+        }
+    }
+}
+
+impl std::error::Error for UnitError {}
+
+/// What plain operators `+` and `-` on [`MksVal`](super::MksVal) do
+/// when units of operands do not match, and what `sqrt`, `cbrt`, `root` and `powf`
+/// do when unit powers do not divide evenly.
+///
+/// Debug builds always panic, release builds follow the policy
+/// selected with cargo feature or cfg flag:
+///
+/// - default: panic;
+/// - feature `mismatch-nan`: return NaN value with unit of left operand;
+/// - `RUSTFLAGS="--cfg mks_unchecked"`: do not store and check units at all, this is the fastest option;
+///   [`MksVal`](super::MksVal) becomes a transparent wrapper of `f64`
+///   without field `unit`, and checked operations never fail.
+///   This changes the API, so it is a flag set by the final build, not a cargo feature.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MismatchPolicy {
+    /// Panic with [`UnitError`] message
+    Panic,
+    /// Return NaN
+    Nan,
+    /// Skip the check, units are not stored
+    Unchecked,
+}
+
+/// Unit mismatch policy of this build.
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// let unchecked = MISMATCH_POLICY == MismatchPolicy::Unchecked;
+/// assert_eq!(unchecked, std::mem::size_of::<MksVal>() == std::mem::size_of::<f64>());
+/// ```
+pub const MISMATCH_POLICY: MismatchPolicy =
+    if cfg!(debug_assertions) { MismatchPolicy::Panic }
+    else if cfg!(not(mks_checked)) { MismatchPolicy::Unchecked }
+    else if cfg!(feature = "mismatch-nan") { MismatchPolicy::Nan }
+    else { MismatchPolicy::Panic };
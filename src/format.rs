@@ -0,0 +1,378 @@
+//! Formatting of MKS values
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+
+use std::fmt;
+use super::{MksVal, MksNum, MksUnit, Term, Terms, render_terms, render_terms_with};
+use super::scale::SI_PREFIXES;
+
+/// Notation of units and numbers.
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// # #[cfg(mks_checked)] {
+/// let accel = FormatOptions::default();
+/// assert_eq!(GRAV_ACCEL_UNIT.display(accel).to_string(), "m / s^2");
+/// let style = |style| FormatOptions { style, ..Default::default() };
+/// assert_eq!(GRAV_ACCEL_UNIT.display(style(UnitStyle::Unicode)).to_string(), "m·s⁻²");
+/// assert_eq!(GRAV_ACCEL_UNIT.display(style(UnitStyle::Latex)).to_string(), r"\mathrm{m}\,\mathrm{s}^{-2}");
+/// assert_eq!(GRAV_ACCEL_UNIT.display(style(UnitStyle::Siunitx)).to_string(), r"\si{\metre\per\second\squared}");
+/// assert_eq!(GRAV_ACCEL_UNIT.display(style(UnitStyle::Html)).to_string(), "m / s<sup>2</sup>");
+/// let g = MksVal::new(1.0, f64::GRAV_ACCEL, GRAV_ACCEL_UNIT);
+/// assert_eq!(format!("{:.2}", g.display(style(UnitStyle::Siunitx))), r"\SI{9.81}{\metre\per\second\squared}");
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum UnitStyle {
+    /// Plain ASCII like `m / s^2`, same as [`MksUnit::as_string`]
+    #[default]
+    Ascii,
+    /// Unicode superscripts like `m·s⁻²`
+    Unicode,
+    /// LaTeX math like `\mathrm{m}\,\mathrm{s}^{-2}`
+    Latex,
+    /// LaTeX `siunitx` package like `\SI{9.81}{\metre\per\second\squared}`
+    Siunitx,
+    /// HTML like `m / s<sup>2</sup>`
+    Html,
+}
+
+/// Options of formatting [`MksVal`] and [`MksUnit`], see [`MksVal::display`] and [`MksUnit::display`].
+///
+/// Precision from format string, like `{:.3}`, applies to the printed number
+/// unless significant figures are given.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct FormatOptions {
+    /// Pick SI prefix for the first unit symbol, like `mm` for 1.83e-3 m
+    pub si_prefix: bool,
+    /// Engineering notation, exponent is multiple of 3;
+    /// used for units that can not take SI prefix when `si_prefix` is on
+    pub engineering: bool,
+    /// Round to number of significant figures
+    pub sig_figs: Option<usize>,
+    /// Write unit with names of SI derived units, see [`MksUnit::as_named_string`]
+    pub derived_names: bool,
+    /// Notation of units and numbers
+    pub style: UnitStyle,
+}
+
+/// Helper to print [`MksVal`] with [`FormatOptions`], returned by [`MksVal::display`].
+#[derive(Debug, Copy, Clone)]
+pub struct MksValDisplay<'a, T = f64> {
+    val: &'a MksVal<T>,
+    opts: FormatOptions,
+}
+
+impl<T: MksNum> MksVal<T> {
+    /// Return object that formats value with options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let len = MksVal::new(6.0, f64::FOOT, FOOT_UNIT);
+    /// let prefixed = FormatOptions { si_prefix: true, ..Default::default() };
+    /// assert_eq!(format!("{:.2}", len.display(prefixed)), "1.83 m");
+    /// let thickness = MksVal::new(1.83e-3, f64::METER, METER_UNIT);
+    /// assert_eq!(format!("{}", thickness.display(prefixed)), "1.83 mm");
+    /// let eng = FormatOptions { engineering: true, sig_figs: Some(3), ..Default::default() };
+    /// assert_eq!(format!("{}", thickness.display(eng)), "1.83e-3 m");
+    /// let c = MksVal::new(1.0, f64::SPEED_OF_LIGHT, SPEED_OF_LIGHT_UNIT);
+    /// assert_eq!(format!("{}", c.display(FormatOptions { sig_figs: Some(3), ..prefixed })), "300 Mm / s");
+    /// let energy = MksVal::new(1500.0, f64::JOULE, JOULE_UNIT);
+    /// assert_eq!(format!("{}", energy.display(FormatOptions { derived_names: true, ..prefixed })), "1.5 kJ");
+    /// # }
+    /// ```
+    pub fn display(&self, opts: FormatOptions) -> MksValDisplay<'_, T> {
+        MksValDisplay { val: self, opts }
+    }
+}
+
+/// Helper to print [`MksUnit`] with [`FormatOptions`], returned by [`MksUnit::display`].
+#[derive(Debug, Copy, Clone)]
+pub struct MksUnitDisplay<'a> {
+    unit: &'a MksUnit,
+    opts: FormatOptions,
+}
+
+impl MksUnit {
+    /// Return object that formats unit, without brackets, with options;
+    /// options for numbers do not apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// let opts = FormatOptions { derived_names: true, style: UnitStyle::Unicode, ..Default::default() };
+    /// assert_eq!(MOLAR_GAS_UNIT.display(opts).to_string(), "J·K⁻¹·mol⁻¹");
+    /// ```
+    pub fn display(&self, opts: FormatOptions) -> MksUnitDisplay<'_> {
+        MksUnitDisplay { unit: self, opts }
+    }
+}
+
+impl fmt::Display for MksUnitDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms = self.unit.terms(self.opts.derived_names);
+        let unit = render_unit(&terms, self.opts.style);
+        match self.opts.style {
+            UnitStyle::Siunitx => write!(f, "\\si{{{}}}", unit),
+            _ => f.write_str(&unit),
+        }
+    }
+}
+
+/// Write digits of integer as Unicode superscripts.
+fn superscript(n: i32) -> String {
+    n.to_string().chars().map(|c| match c {
+        '-' => '⁻',
+        '0' => '⁰', '1' => '¹', '2' => '²', '3' => '³', '4' => '⁴',
+        '5' => '⁵', '6' => '⁶', '7' => '⁷', '8' => '⁸', _ => '⁹',
+    }).collect()
+}
+
+/// LaTeX for unit symbol, possibly with prefix.
+fn latex_symbol(symbol: &str) -> String {
+    symbol.replace('µ', "\\mu ").replace('Ω', "\\Omega").replace('Å', "\\AA")
+}
+
+/// `siunitx` macros of unit symbols
+const SIUNITX_UNITS: [(&str, &str); 20] = [
+    ("m", "metre"), ("g", "gram"), ("kg", "kilogram"), ("s", "second"), ("A", "ampere"),
+    ("K", "kelvin"), ("mol", "mole"), ("cd", "candela"), ("J", "joule"), ("W", "watt"),
+    ("N", "newton"), ("Pa", "pascal"), ("C", "coulomb"), ("V", "volt"), ("Ω", "ohm"),
+    ("F", "farad"), ("H", "henry"), ("Wb", "weber"), ("T", "tesla"), ("Hz", "hertz"),
+];
+
+/// `siunitx` macros of SI prefixes
+const SIUNITX_PREFIXES: [(&str, &str); 20] = [
+    ("Q", "quetta"), ("R", "ronna"), ("Y", "yotta"), ("Z", "zetta"), ("E", "exa"), ("P", "peta"), ("T", "tera"), ("G", "giga"),
+    ("M", "mega"), ("k", "kilo"), ("m", "milli"), ("µ", "micro"), ("n", "nano"), ("p", "pico"),
+    ("f", "femto"), ("a", "atto"), ("z", "zepto"), ("y", "yocto"), ("r", "ronto"), ("q", "quecto"),
+];
+
+/// `siunitx` macros for unit symbol, possibly with prefix.
+fn siunitx_symbol(symbol: &str) -> String {
+    let unit = |s: &str| SIUNITX_UNITS.iter().find(|&&(sym, _)| sym == s).map(|&(_, name)| name);
+    if let Some(name) = unit(symbol) {
+        return format!("\\{}", name);
+    }
+    SIUNITX_PREFIXES.iter()
+        .find_map(|&(prefix, prefix_name)| {
+            let name = unit(symbol.strip_prefix(prefix)?)?;
+            Some(format!("\\{}\\{}", prefix_name, name))
+        })
+        .unwrap_or_else(|| symbol.to_string())
+}
+
+/// Terms with positive powers first.
+fn numerator_first(terms: &[Term]) -> impl Iterator<Item = &Term> {
+    terms.iter().filter(|(_, (p, _))| *p > 0).chain(terms.iter().filter(|(_, (p, _))| *p < 0))
+}
+
+/// Write unit terms in style.
+fn render_unit(terms: &[Term], style: UnitStyle) -> String {
+    match style {
+        UnitStyle::Ascii => render_terms(terms),
+        UnitStyle::Unicode => numerator_first(terms)
+            .map(|(symbol, (p, d))| match (p, d) {
+                (1, 1) => symbol.clone(),
+                (p, 1) => format!("{}{}", symbol, superscript(i32::from(*p))),
+                (p, d) => format!("{}{}ᐟ{}", symbol, superscript(i32::from(*p)), superscript(i32::from(*d))),
+            })
+            .collect::<Vec<_>>().join("·"),
+        UnitStyle::Latex => numerator_first(terms)
+            .map(|(symbol, (p, d))| {
+                let symbol = format!("\\mathrm{{{}}}", latex_symbol(symbol));
+                match (p, d) {
+                    (1, 1) => symbol,
+                    (p, 1) => format!("{}^{{{}}}", symbol, p),
+                    (p, d) => format!("{}^{{{}/{}}}", symbol, p, d),
+                }
+            })
+            .collect::<Vec<_>>().join("\\,"),
+        UnitStyle::Siunitx => numerator_first(terms)
+            .map(|(symbol, (p, d))| {
+                let per = if *p < 0 { "\\per" } else { "" };
+                let power = match (p.abs(), *d) {
+                    (1, 1) => String::new(),
+                    (2, 1) => "\\squared".to_string(),
+                    (3, 1) => "\\cubed".to_string(),
+                    (p, 1) => format!("\\tothe{{{}}}", p),
+                    (p, d) => format!("\\tothe{{{}/{}}}", p, d),
+                };
+                format!("{}{}{}", per, siunitx_symbol(symbol), power)
+            })
+            .collect(),
+        UnitStyle::Html => render_terms_with(terms, |p, d| {
+            if d > 1 { format!("<sup>{}/{}</sup>", p, d) }
+            else if p > 1 { format!("<sup>{}</sup>", p) }
+            else { String::new() }
+        }),
+    }
+}
+
+/// Round to `n` significant figures.
+pub(crate) fn round_sig(val: f64, n: usize) -> f64 {
+    if val == 0.0 || !val.is_finite() { return val; }
+    let exp = val.abs().log10().floor() as i32;
+    let scale = 10.0_f64.powi(n.max(1) as i32 - 1 - exp);
+    (val * scale).round() / scale
+}
+
+/// Number of decimals to print `val` with `n` significant figures.
+fn sig_decimals(val: f64, n: usize) -> usize {
+    if val == 0.0 || !val.is_finite() { return n.saturating_sub(1); }
+    let exp = val.abs().log10().floor() as i32;
+    (n.max(1) as i32 - 1 - exp).max(0) as usize
+}
+
+/// Split value into mantissa and exponent that is multiple of 3.
+fn engineering(val: f64) -> (f64, i32) {
+    if val == 0.0 || !val.is_finite() { return (val, 0); }
+    let exp = val.abs().log10().floor() as i32;
+    let exp = exp.div_euclid(3) * 3;
+    (val / 10.0_f64.powi(exp), exp)
+}
+
+/// Value scaled by SI prefix and unit terms with the prefix,
+/// `None` if the unit does not start with single power of a symbol
+/// or there is no prefix for the value.
+fn with_si_prefix(val: f64, mut terms: Terms) -> Option<(f64, Terms)> {
+    let (symbol, power) = terms.iter_mut().find(|(_, (p, _))| *p > 0)?;
+    if *power != (1, 1) || val == 0.0 || !val.is_finite() { return None; }
+    // mass takes prefix on gram
+    let val = if symbol == "kg" { *symbol = "g".to_string(); val * 1000.0 } else { val };
+    let abs = val.abs();
+    if (1.0..1000.0).contains(&abs) {
+        return Some((val, terms));
+    }
+    let &(prefix, factor) = SI_PREFIXES.iter()
+        .filter(|&&(prefix, factor)| prefix != "u" && prefix != "μ" && factor.log10().round() as i32 % 3 == 0)
+        .find(|&&(_, factor)| (1.0..1000.0).contains(&(abs / factor)))?;
+    symbol.insert_str(0, prefix);
+    Some((val / factor, terms))
+}
+
+/// Number with given number of decimals, or shortest representation,
+/// and power of 10 in style.
+fn render_number(val: f64, decimals: Option<usize>, exp: i32, style: UnitStyle) -> String {
+    let mut s = match decimals {
+        Some(decimals) => format!("{:.*}", decimals, val),
+        None => format!("{}", val),
+    };
+    if exp != 0 {
+        match style {
+            UnitStyle::Ascii | UnitStyle::Siunitx => s.push_str(&format!("e{}", exp)),
+            UnitStyle::Unicode => s.push_str(&format!("×10{}", superscript(exp))),
+            UnitStyle::Latex => s.push_str(&format!("\\times10^{{{}}}", exp)),
+            UnitStyle::Html => s.push_str(&format!("&times;10<sup>{}</sup>", exp)),
+        }
+    }
+    s
+}
+
+impl<T: MksNum> fmt::Display for MksValDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let opts = self.opts;
+        let mut val = self.val.val.to_f64();
+        if let Some(n) = opts.sig_figs { val = round_sig(val, n); }
+
+        let terms = self.val.unit_or_scalar().terms(opts.derived_names);
+        let prefixed = if opts.si_prefix { with_si_prefix(val, terms.clone()) } else { None };
+        let has_prefix = prefixed.is_some();
+        let (mut val, unit) = match prefixed {
+            Some((val, terms)) => (val, render_unit(&terms, opts.style)),
+            None => (val, render_unit(&terms, opts.style)),
+        };
+
+        let mut exp = 0;
+        if opts.engineering && !has_prefix {
+            (val, exp) = engineering(val);
+        }
+        if let Some(n) = opts.sig_figs {
+            val = round_sig(val, n);
+            // rounding may carry into next power of 1000
+            if opts.engineering && exp != 0 && val.abs() >= 1000.0 {
+                val /= 1000.0;
+                exp += 3;
+            }
+        }
+
+        let decimals = match opts.sig_figs {
+            Some(n) => Some(sig_decimals(val, n)),
+            None => f.precision(),
+        };
+        let number = render_number(val, decimals, exp, opts.style);
+        match (opts.style, unit.is_empty()) {
+            (_, true) if opts.style != UnitStyle::Siunitx => f.write_str(&number),
+            (UnitStyle::Siunitx, true) => write!(f, "\\num{{{}}}", number),
+            (UnitStyle::Siunitx, false) => write!(f, "\\SI{{{}}}{{{}}}", number, unit),
+            (UnitStyle::Latex, false) => write!(f, "{}\\,{}", number, unit),
+            _ => write!(f, "{} {}", number, unit),
+        }
+    }
+}
+
+impl<T: MksNum> fmt::Display for MksVal<T> {
+    /// Print value followed by unit, precision applies to the value,
+    /// alternate form `{:#}` uses names of SI derived units.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let g = MksVal::new(1.0, f64::GRAV_ACCEL, GRAV_ACCEL_UNIT);
+    /// assert_eq!(format!("{:.2}", g), "9.81 m / s^2");
+    /// assert_eq!(format!("{}", MksVal::new_scalar(0.5)), "0.5");
+    /// let energy = MksVal::new(2.0, f64::ERG, ERG_UNIT);
+    /// assert_eq!(format!("{:#}", energy), "0.0000002 J");
+    /// # }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let opts = FormatOptions { derived_names: f.alternate(), ..Default::default() };
+        fmt::Display::fmt(&self.display(opts), f)
+    }
+}
+
+impl<T: MksNum + fmt::LowerExp> fmt::LowerExp for MksVal<T> {
+    /// Print value in scientific notation followed by unit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let c = MksVal::new(1.0, f64::SPEED_OF_LIGHT, SPEED_OF_LIGHT_UNIT);
+    /// assert_eq!(format!("{:.3e}", c), "2.998e8 m / s");
+    /// # }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(decimals) => write!(f, "{:.*e}", decimals, self.val)?,
+            None => write!(f, "{:e}", self.val)?,
+        }
+        let unit = self.unit_or_scalar().as_string();
+        if !unit.is_empty() { write!(f, " {}", unit)?; }
+        Ok(())
+    }
+}
+
+impl<T: MksNum + fmt::UpperExp> fmt::UpperExp for MksVal<T> {
+    /// Print value in scientific notation with capital `E` followed by unit.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(decimals) => write!(f, "{:.*E}", decimals, self.val)?,
+            None => write!(f, "{:E}", self.val)?,
+        }
+        let unit = self.unit_or_scalar().as_string();
+        if !unit.is_empty() { write!(f, " {}", unit)?; }
+        Ok(())
+    }
+}
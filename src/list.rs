@@ -6,327 +6,637 @@
 
 use super::*;
 
-/// List of MKS constants name
-pub enum Name {
-    /// Speef of light
-    SpeedOfLight,
-    /// Gravitational constant
-    GravitationalConstant,
-    /// Plancks h constant
-    PlancksConstantH,
-    /// Plancks h-bar constant
-    PlancksConstantHBar,
-    /// Astronomical unit
-    AstronomicalUnit,
-    /// Light year
-    LightYear,
-    /// Parsec
-    Parsec,
-    /// Acceleration
-    GravAccel,
-    /// Electron Volt
-    ElectronVolt,
-    /// Mass of electron
-    MassElectron,
-    /// Mass of muon
-    MassMuon,
-    /// Mass of proton
-    MassProton,
-    /// Mass of neutron
-    MassNeutron,
-    /// Rydberg
-    Rydberg,
-    /// Boltzmann
-    Boltzmann,
-    /// Molar
-    MolarGas,
-    /// Standard gas volume
-    StandardGasVolume,
-    /// Second of time
-    Second,
-    /// Minute of time
-    Minute,
-    /// Hour
-    Hour,
-    /// Day
-    Day,
-    /// Week
-    Week,
-    /// Meter
-    Meter,
-    /// Inch
-    Inch,
-    /// Foot
-    Foot,
-    /// Yard
-    Yard,
-    /// Mile
-    Mile,
-    /// NauticalMile
-    NauticalMile,
-    /// Fathom
-    Fathom,
-    /// Mil
-    Mil,
-    /// Point
-    Point,
-    /// Textpoint
-    Textpoint,
-    /// Micron
-    Micron,
-    /// Angstrom
-    Angstrom,
-    /// Hectare
-    Hectare,
-    /// Acre
-    Acre,
-    /// Barn
-    Barn,
-    /// Liter,
-    Liter,
-    /// US gallon
-    UsGallon,
-    /// Quart
-    Quart,
-    /// Pint
-    Pint,
-    /// Cup
-    Cup,
-    /// Fluid ounce
-    FluidOunce,
-    /// Tablespoon
-    Tablespoon,
-    /// Teaspoon,
-    Teaspoon,
-    /// Canadian gallon
-    CanadianGallon,
-    /// UK gallon
-    UkGallon,
-    /// miles/h
-    MilesPerHour,
-    /// km/h
-    KilometersPerHour,
-    /// Knot
-    Knot,
-    /// kg
-    Kilogram,
-    /// Pound mass
-    PoundMass,
-    /// Ounce mass
-    OunceMass,
-    /// Ton
-    Ton,
-    /// Metric ton
-    MetricTon,
-    /// UK ton
-    UkTon,
-    /// Troy ounce
-    TroyOunce,
-    /// Carat
-    Carat,
-    /// Unified atomic mass
-    UnifiedAtomicMass,
-    /// Gram force
-    GramForce,
-    /// Pound force
-    PoundForce,
-    /// Kilopound force
-    KilopoundForce,
-    /// Poundal
-    Poundal,
-    /// Calorie
-    Calorie,
-    /// BTU
-    Btu,
-    /// Therm
-    Therm,
-    /// Horsepower
-    Horsepower,
-    /// Bar
-    Bar,
-    /// Standard atmosphere
-    StdAtmosphere,
-    /// Torr
-    Torr,
-    /// Meter of mercury
-    MeterOfMercury,
-    /// Inch of mercury
-    InchOfMercury,
-    /// Inch of water
-    InchOfWater,
-    /// PSI
-    Psi,
-    /// Poise
-    Poise,
-    /// Stokes
-    Stokes,
-    /// STILB
-    Stilb,
-    /// Lumen
-    Lumen,
-    /// Lux
-    Lux,
-    /// Phot
-    Phot,
-    /// Footcandle
-    Footcandle,
-    /// Lambert
-    Lambert,
-    /// Footlambert
-    Footlambert,
-    /// Curie
-    Curie,
-    /// Roentgen
-    Roentgen,
-    /// Rad
-    Rad,
-    /// Solar mass
-    SolarMass,
-    /// Bohr radius
-    BohrRadius,
-    /// Newton
-    Newton,
-    /// Dyne
-    Dyne,
-    /// Joule
-    Joule,
-    /// Erg
-    Erg,
-    /// STEFAN_BOLTZMANN_CONSTANT
-    StefanBolzmannConstant,
-    /// THOMSON_CROSS_SECTION
-    ThomsonCrossSection,
-    /// Bohr magneton
-    BohrMagneton,
-    /// Nuclear magneton
-    NuclearMagneton,
-    /// ELECTRON_MAGNETIC_MOMENT
-    ElectronMagneticMoment,
-    /// PROTON_MAGNETIC_MOMENT
-    ProtonMagneticMoment,
-    /// Faraday
-    Faraday,
-    /// Electron charge
-    ElectronCharge,
-    /// VACUUM_PERMITTIVITY
-    VacuumPermittivity,
-    /// VACUUM_PERMEABILITY
-    VacuumPermeability,
-    /// Debye
-    Debye,
-    /// Gauss
-    Gauss,
-}
-
 /// Record in the list of constants
 pub type MksTuple<'a> = (Name, MksUnit, f64, &'a str);
 
-/// List of MKS units with dimentions and factors
-pub const UNITS: [MksTuple; 104] = [
-    (Name::SpeedOfLight,           SPEED_OF_LIGHT_UNIT,           f64::SPEED_OF_LIGHT,          "Speed of light"),
-    (Name::GravitationalConstant,  GRAVITATIONAL_CONSTANT_UNIT,   f64::GRAVITATIONAL_CONSTANT,  "Gravitational constant"),
-    (Name::PlancksConstantH,       PLANCKS_CONSTANT_H_UNIT,       f64::PLANCKS_CONSTANT_H,      "Planck's constant h"),
-    (Name::PlancksConstantHBar,    PLANCKS_CONSTANT_HBAR_UNIT,    f64::PLANCKS_CONSTANT_HBAR,   "Planck's constant h bar"),
-    (Name::AstronomicalUnit,       ASTRONOMICAL_UNIT_UNIT,        f64::ASTRONOMICAL_UNIT,       "Astronomical unit"),
-    (Name::LightYear,              LIGHT_YEAR_UNIT,               f64::LIGHT_YEAR,              "Light year"),
-    (Name::Parsec,                 PARSEC_UNIT,                   f64::PARSEC,                  "Parsec"),
-    (Name::GravAccel,              GRAV_ACCEL_UNIT,               f64::GRAV_ACCEL,              "Grav Acceleration"),
-    (Name::ElectronVolt,           ELECTRON_VOLT_UNIT,            f64::ELECTRON_VOLT,           "Electron Volt"),
-    (Name::MassElectron,           MASS_ELECTRON_UNIT,            f64::MASS_ELECTRON,           "Mass of electron"),
-    (Name::MassMuon,               MASS_MUON_UNIT,                f64::MASS_MUON,               "Mass of muon"),
-    (Name::MassProton,             MASS_PROTON_UNIT,              f64::MASS_PROTON,             "Mass of proton"),
-    (Name::MassNeutron,            MASS_NEUTRON_UNIT,             f64::MASS_NEUTRON,            "Mass of neutron"),
-    (Name::Rydberg,                RYDBERG_UNIT,                  f64::RYDBERG,                 "Rydberg"),
-    (Name::Boltzmann,              BOLTZMANN_UNIT,                f64::BOLTZMANN,               "Boltzmann"),
-    (Name::MolarGas,               MOLAR_GAS_UNIT,                f64::MOLAR_GAS,               "Molar gas"),
-    (Name::StandardGasVolume,      STANDARD_GAS_VOLUME_UNIT,      f64::STANDARD_GAS_VOLUME,     "Standard gas volume"),
-    (Name::Second,                 SECOND_UNIT,                   f64::SECOND,                  "Second"),
-    (Name::Minute,                 MINUTE_UNIT,                   f64::MINUTE,                  "Minute"),
-    (Name::Hour,                   HOUR_UNIT,                     f64::HOUR,                    "Hour"),
-    (Name::Day,                    DAY_UNIT,                      f64::DAY,                     "Day"),
-    (Name::Week,                   WEEK_UNIT,                     f64::WEEK,                    "Week"),
-    (Name::Meter,                  METER_UNIT,                    f64::METER,                   "Meter"),
-    (Name::Inch,                   INCH_UNIT,                     f64::INCH,                    "Inch"),
-    (Name::Foot,                   FOOT_UNIT,                     f64::FOOT,                    "Foot"),
-    (Name::Yard,                   YARD_UNIT,                     f64::YARD,                    "Yard"),
-    (Name::Mile,                   MILE_UNIT,                     f64::MILE,                    "Mile"),
-    (Name::NauticalMile,           NAUTICAL_MILE_UNIT,            f64::NAUTICAL_MILE,           "Nautical mile"),
-    (Name::Fathom,                 FATHOM_UNIT,                   f64::FATHOM,                  "Fathom"),
-    (Name::Mil,                    MIL_UNIT,                      f64::MIL,                     "Mil"),
-    (Name::Point,                  POINT_UNIT,                    f64::POINT,                   "Point"),
-    (Name::Textpoint,              TEXPOINT_UNIT,                 f64::TEXPOINT,                "Textpoint"),
-    (Name::Micron,                 MICRON_UNIT,                   f64::MICRON,                  "Micron"),
-    (Name::Angstrom,               ANGSTROM_UNIT,                 f64::ANGSTROM,                "Angstrom"),
-    (Name::Hectare,                HECTARE_UNIT,                  f64::HECTARE,                 "Hectare"),
-    (Name::Acre,                   ACRE_UNIT,                     f64::ACRE,                    "Acre"),
-    (Name::Barn,                   BARN_UNIT,                     f64::BARN,                    "Barn"),
-    (Name::Liter,                  LITER_UNIT,                    f64::LITER,                   "Liter"),
-    (Name::UsGallon,               US_GALLON_UNIT,                f64::US_GALLON,               "US gallon"),
-    (Name::Quart,                  QUART_UNIT,                    f64::QUART,                   "Quart"),
-    (Name::Pint,                   PINT_UNIT,                     f64::PINT,                    "Pint"),
-    (Name::Cup,                    CUP_UNIT,                      f64::CUP,                     "Cup"),
-    (Name::FluidOunce,             FLUID_OUNCE_UNIT,              f64::FLUID_OUNCE,             "Fluid ounce"),
-    (Name::Tablespoon,             TABLESPOON_UNIT,               f64::TABLESPOON,              "Tablespoon"),
-    (Name::Teaspoon,               TEASPOON_UNIT,                 f64::TEASPOON,                "Teaspoon"),
-    (Name::CanadianGallon,         CANADIAN_GALLON_UNIT,          f64::CANADIAN_GALLON,         "Canadian gallon"),
-    (Name::UkGallon,               UK_GALLON_UNIT,                f64::UK_GALLON,               "UK gallon"),
-    (Name::MilesPerHour,           MILES_PER_HOUR_UNIT,           f64::MILES_PER_HOUR,          "Miles per hour"),
-    (Name::KilometersPerHour,      KILOMETERS_PER_HOUR_UNIT,      f64::KILOMETERS_PER_HOUR,     "Kilometers per hour"),
-    (Name::Knot,                   KNOT_UNIT,                     f64::KNOT,                    "Knot"),
-    (Name::Kilogram,               KILOGRAM_UNIT,                 f64::KILOGRAM,                "Kilogram"),
-    (Name::PoundMass,              POUND_MASS_UNIT,               f64::POUND_MASS,              "Pound mass"),
-    (Name::OunceMass,              OUNCE_MASS_UNIT,               f64::OUNCE_MASS,              "Ounce mass"),
-    (Name::Ton,                    TON_UNIT,                      f64::TON,                     "Ton"),
-    (Name::MetricTon,              METRIC_TON_UNIT,               f64::METRIC_TON,              "Metric ton"),
-    (Name::UkTon,                  UK_TON_UNIT,                   f64::UK_TON,                  "UK ton"),
-    (Name::TroyOunce,              TROY_OUNCE_UNIT,               f64::TROY_OUNCE,              "Troy ounce"),
-    (Name::Carat,                  CARAT_UNIT,                    f64::CARAT,                   "Carat"),
-    (Name::UnifiedAtomicMass,      UNIFIED_ATOMIC_MASS_UNIT,      f64::UNIFIED_ATOMIC_MASS,     "Unified atomic mass"),
-    (Name::GramForce,              GRAM_FORCE_UNIT,               f64::GRAM_FORCE,              "Gram force"),
-    (Name::PoundForce,             POUND_FORCE_UNIT,              f64::POUND_FORCE,             "Pound force"),
-    (Name::KilopoundForce,         KILOPOUND_FORCE_UNIT,          f64::KILOPOUND_FORCE,         "Kilopound force"),
-    (Name::Poundal,                POUNDAL_UNIT,                  f64::POUNDAL,                 "Poundal"),
-    (Name::Calorie,                CALORIE_UNIT,                  f64::CALORIE,                 "Calorie"),
-    (Name::Btu,                    BTU_UNIT,                      f64::BTU,                     "Btu"),
-    (Name::Therm,                  THERM_UNIT,                    f64::THERM,                   "Therm"),
-    (Name::Horsepower,             HORSEPOWER_UNIT,               f64::HORSEPOWER,              "Horsepower"),
-    (Name::Bar,                    BAR_UNIT,                      f64::BAR,                     "Bar"),
-    (Name::StdAtmosphere,          STD_ATMOSPHERE_UNIT,           f64::STD_ATMOSPHERE,          "STD atmosphere"),
-    (Name::Torr,                   TORR_UNIT,                     f64::TORR,                    "Torr"),
-    (Name::MeterOfMercury,         METER_OF_MERCURY_UNIT,         f64::METER_OF_MERCURY,        "Meter of mercury"),
-    (Name::InchOfMercury,          INCH_OF_MERCURY_UNIT,          f64::INCH_OF_MERCURY,         "Inch of mercury"),
-    (Name::InchOfWater,            INCH_OF_WATER_UNIT,            f64::INCH_OF_WATER,           "Inch of water"),
-    (Name::Psi,                    PSI_UNIT,                      f64::PSI,                     "Psi"),
-    (Name::Poise,                  POISE_UNIT,                    f64::POISE,                   "Poise"),
-    (Name::Stokes,                 STOKES_UNIT,                   f64::STOKES,                  "Stokes"),
-    (Name::Stilb,                  STILB_UNIT,                    f64::STILB,                   "Stilb"),
-    (Name::Lumen,                  LUMEN_UNIT,                    f64::LUMEN,                   "Lumen"),
-    (Name::Lux,                    LUX_UNIT,                      f64::LUX,                     "Lux"),
-    (Name::Phot,                   PHOT_UNIT,                     f64::PHOT,                    "Phot"),
-    (Name::Footcandle,             FOOTCANDLE_UNIT,               f64::FOOTCANDLE,              "Footcandle"),
-    (Name::Lambert,                LAMBERT_UNIT,                  f64::LAMBERT,                 "Lambert"),
-    (Name::Footlambert,            FOOTLAMBERT_UNIT,              f64::FOOTLAMBERT,             "Footlambert"),
-    (Name::Curie,                  CURIE_UNIT,                    f64::CURIE,                   "Curie"),
-    (Name::Roentgen,               ROENTGEN_UNIT,                 f64::ROENTGEN,                "Roentgen"),
-    (Name::Rad,                    RAD_UNIT,                      f64::RAD,                     "Rad"),
-    (Name::SolarMass,              SOLAR_MASS_UNIT,               f64::SOLAR_MASS,              "Solar mass"),
-    (Name::BohrRadius,             BOHR_RADIUS_UNIT,              f64::BOHR_RADIUS,             "Bohr radius"),
-    (Name::Newton,                 NEWTON_UNIT,                   f64::NEWTON,                  "Newton"),
-    (Name::Dyne,                   DYNE_UNIT,                     f64::DYNE,                    "Dyne"),
-    (Name::Joule,                  JOULE_UNIT,                    f64::JOULE,                   "Joule"),
-    (Name::Erg,                    ERG_UNIT,                      f64::ERG,                     "Erg"),
-    (Name::StefanBolzmannConstant, STEFAN_BOLTZMANN_CONSTANT_UNIT,f64::STEFAN_BOLTZMANN_CONSTANT,"STEFAN_BOLTZMANN_CONSTANT"),
-    (Name::ThomsonCrossSection,    THOMSON_CROSS_SECTION_UNIT,    f64::THOMSON_CROSS_SECTION,   "THOMSON_CROSS_SECTION"),
-    (Name::BohrMagneton,           BOHR_MAGNETON_UNIT,            f64::BOHR_MAGNETON,           "Bohr magneton"),
-    (Name::NuclearMagneton,        NUCLEAR_MAGNETON_UNIT,         f64::NUCLEAR_MAGNETON,        "Nuclear magneton"),
-    (Name::ElectronMagneticMoment, ELECTRON_MAGNETIC_MOMENT_UNIT, f64::ELECTRON_MAGNETIC_MOMENT,"Electron magnetic moment"),
-    (Name::ProtonMagneticMoment,   PROTON_MAGNETIC_MOMENT_UNIT,   f64::PROTON_MAGNETIC_MOMENT,  "Proton magnetic moment"),
-    (Name::Faraday,                FARADAY_UNIT,                  f64::FARADAY,                 "Faraday"),
-    (Name::ElectronCharge,         ELECTRON_CHARGE_UNIT,          f64::ELECTRON_CHARGE,         "Electron charge"),
-    (Name::VacuumPermittivity,     VACUUM_PERMITTIVITY_UNIT,      f64::VACUUM_PERMITTIVITY,     "VACUUM_PERMITTIVITY"),
-    (Name::VacuumPermeability,     VACUUM_PERMEABILITY_UNIT,      f64::VACUUM_PERMITTIVITY,     "VACUUM_PERMITTIVITY"),
-    (Name::Debye,                  DEBYE_UNIT,                    f64::DEBYE,                   "Debye"),
-    (Name::Gauss,                  GAUSS_UNIT,                    f64::GAUSS,                   "Gauss"),
+/// Expand rows of `mks_table!` into [`Name`] and [`UNITS`].
+macro_rules! mks_names {
+    ($($name:ident, $cnst:ident, $unit:ident, [$($pow:expr),*], $val:expr, $descr:literal;)*) => {
+        /// List of MKS constants name
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum Name {
+            $(
+                #[doc = $descr]
+                $name,
+            )*
+        }
+
+        /// List of MKS units with dimentions and factors
+        pub const UNITS: [MksTuple; [$(Name::$name),*].len()] = [
+            $(
+                (Name::$name, $unit, f64::$cnst, $descr),
+            )*
+        ];
+
+        /// Catalogue of MKS constants and units with symbols, names and categories,
+        /// in the same order as [`UNITS`]
+        pub static CATALOGUE: [UnitInfo; [$(Name::$name),*].len()] = [
+            $(
+                UnitInfo::new(Name::$name, $unit, f64::$cnst, $descr),
+            )*
+        ];
+    };
+}
+
+mks_table!(mks_names);
+
+/// Short symbols of units in the list, for example `ft` for [`Name::Foot`]
+pub const SYMBOLS: [(&str, Name); 77] = [
+    ("s",     Name::Second),
+    ("min",   Name::Minute),
+    ("h",     Name::Hour),
+    ("d",     Name::Day),
+    ("wk",    Name::Week),
+    ("m",     Name::Meter),
+    ("in",    Name::Inch),
+    ("ft",    Name::Foot),
+    ("yd",    Name::Yard),
+    ("mi",    Name::Mile),
+    ("nmi",   Name::NauticalMile),
+    ("ftm",   Name::Fathom),
+    ("mil",   Name::Mil),
+    ("Å",     Name::Angstrom),
+    ("ha",    Name::Hectare),
+    ("ac",    Name::Acre),
+    ("b",     Name::Barn),
+    ("L",     Name::Liter),
+    ("l",     Name::Liter),
+    ("gal",   Name::UsGallon),
+    ("qt",    Name::Quart),
+    ("pt",    Name::Pint),
+    ("cup",   Name::Cup),
+    ("floz",  Name::FluidOunce),
+    ("tbsp",  Name::Tablespoon),
+    ("tsp",   Name::Teaspoon),
+    ("mph",   Name::MilesPerHour),
+    ("kph",   Name::KilometersPerHour),
+    ("kn",    Name::Knot),
+    ("kt",    Name::Knot),
+    ("kg",    Name::Kilogram),
+    ("lb",    Name::PoundMass),
+    ("oz",    Name::OunceMass),
+    ("ton",   Name::Ton),
+    ("t",     Name::MetricTon),
+    ("ozt",   Name::TroyOunce),
+    ("ct",    Name::Carat),
+    ("u",     Name::UnifiedAtomicMass),
+    ("Da",    Name::UnifiedAtomicMass),
+    ("gf",    Name::GramForce),
+    ("lbf",   Name::PoundForce),
+    ("kip",   Name::KilopoundForce),
+    ("pdl",   Name::Poundal),
+    ("cal",   Name::Calorie),
+    ("BTU",   Name::Btu),
+    ("Btu",   Name::Btu),
+    ("thm",   Name::Therm),
+    ("hp",    Name::Horsepower),
+    ("bar",   Name::Bar),
+    ("atm",   Name::StdAtmosphere),
+    ("Torr",  Name::Torr),
+    ("torr",  Name::Torr),
+    ("mHg",   Name::MeterOfMercury),
+    ("inHg",  Name::InchOfMercury),
+    ("psi",   Name::Psi),
+    ("P",     Name::Poise),
+    ("St",    Name::Stokes),
+    ("sb",    Name::Stilb),
+    ("lm",    Name::Lumen),
+    ("lx",    Name::Lux),
+    ("ph",    Name::Phot),
+    ("fc",    Name::Footcandle),
+    ("fL",    Name::Footlambert),
+    ("Ci",    Name::Curie),
+    ("R",     Name::Roentgen),
+    ("rad",   Name::Rad),
+    ("eV",    Name::ElectronVolt),
+    ("N",     Name::Newton),
+    ("dyn",   Name::Dyne),
+    ("J",     Name::Joule),
+    ("erg",   Name::Erg),
+    ("G",     Name::Gauss),
+    ("D",     Name::Debye),
+    ("au",    Name::AstronomicalUnit),
+    ("AU",    Name::AstronomicalUnit),
+    ("ly",    Name::LightYear),
+    ("pc",    Name::Parsec),
     ];
 
+/// Record of the list for unit name
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// let (_, unit, factor, descr) = list::record(list::Name::Foot);
+/// assert!(unit == FOOT_UNIT && factor == f64::FOOT && descr == "Foot");
+/// ```
+pub fn record(name: Name) -> MksTuple<'static> {
+    UNITS[name as usize]
+}
+
+/// Factor to convert value in unit `from` to unit `to`, fail if dimensions do not match
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// use list::Name;
+/// assert_eq!(list::conversion_factor(Name::Hour, Name::Minute), Ok(60.0));
+/// assert!((list::conversion_factor(Name::Foot, Name::Inch).unwrap() - 12.0).abs() < 1e-12);
+/// assert!(list::conversion_factor(Name::Foot, Name::Second).is_err());
+/// ```
+pub fn conversion_factor(from: Name, to: Name) -> Result<f64, UnitError> {
+    let (_, from_unit, from_factor, _) = record(from);
+    let (_, to_unit, to_factor, _) = record(to);
+    if from_unit != to_unit {
+        return Err(UnitError::binary(UnitOp::Convert, from_unit, to_unit));
+    }
+    Ok(from_factor / to_factor)
+}
 
+/// Convert value in unit `from` to unit `to`, fail if dimensions do not match
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// use list::Name;
+/// let bar = list::convert(30.0, Name::Psi, Name::Bar).unwrap();
+/// assert!((bar - 2.068).abs() < 1e-3);
+/// let err = list::convert(30.0, Name::Psi, Name::Joule).unwrap_err();
+/// assert_eq!(&err.to_string(), "cannot convert [kg / m s^2] to [m^2 kg / s^2]");
+/// ```
+pub fn convert(value: f64, from: Name, to: Name) -> Result<f64, UnitError> {
+    conversion_factor(from, to).map(|factor| value * factor)
+}
+
+/// Kind of physical quantity of catalogue entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// Fundamental physical constant
+    Constant,
+    /// Time
+    Time,
+    /// Length
+    Length,
+    /// Area
+    Area,
+    /// Volume
+    Volume,
+    /// Speed
+    Speed,
+    /// Acceleration
+    Acceleration,
+    /// Mass
+    Mass,
+    /// Force
+    Force,
+    /// Energy
+    Energy,
+    /// Power
+    Power,
+    /// Pressure
+    Pressure,
+    /// Dynamic and kinematic viscosity
+    Viscosity,
+    /// Luminous flux, illuminance and luminance
+    Light,
+    /// Radioactivity, exposure and absorbed dose
+    Radiation,
+    /// Electric and magnetic quantities
+    Electromagnetic,
+}
+
+/// System of units
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitSystem {
+    /// SI and units accepted for use with SI
+    Si,
+    /// United States customary units
+    UsCustomary,
+    /// British imperial units
+    Imperial,
+    /// Centimetre-gram-second system
+    Cgs,
+}
+
+/// Source of values of units in the catalogue
+pub const SOURCE_GSL: &str = "GSL gsl_const_mks.h";
+
+/// Source of values of physical constants in the catalogue, read from NIST listing at build time
+pub const SOURCE_CODATA: &str = codata_source!();
+
+/// Record of [`CATALOGUE`]
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// let info = list::Name::Foot.info();
+/// assert_eq!(info.symbol, Some("ft"));
+/// assert_eq!(info.plural, "Feet");
+/// assert_eq!(info.category, list::Category::Length);
+/// assert!(info.exact && info.unit == FOOT_UNIT && info.factor == f64::FOOT);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitInfo {
+    /// Name in the list
+    pub name: Name,
+    /// Dimension
+    pub unit: MksUnit,
+    /// Factor to MKS
+    pub factor: f64,
+    /// Description, singular name
+    pub descr: &'static str,
+    /// Main symbol from [`SYMBOLS`], if any
+    pub symbol: Option<&'static str>,
+    /// Plural name, same as description for constants and uncountable units
+    pub plural: &'static str,
+    /// Alternate names
+    pub aliases: &'static [&'static str],
+    /// Kind of physical quantity
+    pub category: Category,
+    /// Systems of units the unit belongs to
+    pub systems: &'static [UnitSystem],
+    /// Value is exact by definition, not measured
+    pub exact: bool,
+    /// Where the value comes from
+    pub source: &'static str,
+}
+
+const EXACT: bool = true;
+const MEASURED: bool = false;
+
+/// Category, exactness, plural and alternate names.
+type Details = (Category, bool, &'static str, &'static [&'static str]);
+
+const fn details(category: Category, exact: bool, plural: &'static str, aliases: &'static [&'static str]) -> Details {
+    (category, exact, plural, aliases)
+}
+
+/// Catalogue details of unit name
+const fn name_details(name: Name) -> Details {
+    use Name::*;
+    use Category::*;
+    match name {
+        SpeedOfLight           => details(Constant, EXACT, "Speed of light", &[]),
+        GravitationalConstant  => details(Constant, MEASURED, "Gravitational constant", &["Newtonian constant of gravitation"]),
+        PlancksConstantH       => details(Constant, MEASURED, "Planck's constant h", &["Planck constant"]),
+        PlancksConstantHBar    => details(Constant, MEASURED, "Planck's constant h bar", &["reduced Planck constant", "Dirac constant"]),
+        AstronomicalUnit       => details(Length, MEASURED, "Astronomical units", &[]),
+        LightYear              => details(Length, MEASURED, "Light years", &["lightyear"]),
+        Parsec                 => details(Length, MEASURED, "Parsecs", &[]),
+        GravAccel              => details(Acceleration, EXACT, "Grav Acceleration", &["standard gravity", "standard acceleration of gravity"]),
+        ElectronVolt           => details(Energy, MEASURED, "Electron Volts", &["electronvolt"]),
+        MassElectron           => details(Constant, MEASURED, "Mass of electron", &["electron mass"]),
+        MassMuon               => details(Constant, MEASURED, "Mass of muon", &["muon mass"]),
+        MassProton             => details(Constant, MEASURED, "Mass of proton", &["proton mass"]),
+        MassNeutron            => details(Constant, MEASURED, "Mass of neutron", &["neutron mass"]),
+        Rydberg                => details(Constant, MEASURED, "Rydberg", &["Rydberg energy"]),
+        Boltzmann              => details(Constant, MEASURED, "Boltzmann", &["Boltzmann constant"]),
+        MolarGas               => details(Constant, MEASURED, "Molar gas", &["molar gas constant", "gas constant"]),
+        StandardGasVolume      => details(Constant, MEASURED, "Standard gas volume", &["molar volume of ideal gas"]),
+        Second                 => details(Time, EXACT, "Seconds", &["sec"]),
+        Minute                 => details(Time, EXACT, "Minutes", &[]),
+        Hour                   => details(Time, EXACT, "Hours", &[]),
+        Day                    => details(Time, EXACT, "Days", &[]),
+        Week                   => details(Time, EXACT, "Weeks", &[]),
+        Meter                  => details(Length, EXACT, "Meters", &["metre"]),
+        Inch                   => details(Length, EXACT, "Inches", &[]),
+        Foot                   => details(Length, EXACT, "Feet", &[]),
+        Yard                   => details(Length, EXACT, "Yards", &[]),
+        Mile                   => details(Length, EXACT, "Miles", &["statute mile"]),
+        NauticalMile           => details(Length, EXACT, "Nautical miles", &[]),
+        Fathom                 => details(Length, EXACT, "Fathoms", &[]),
+        Mil                    => details(Length, EXACT, "Mils", &["thou"]),
+        Point                  => details(Length, EXACT, "Points", &["printer's point"]),
+        Textpoint              => details(Length, EXACT, "Textpoints", &["TeX point"]),
+        Micron                 => details(Length, EXACT, "Microns", &["micrometer", "micrometre"]),
+        Angstrom               => details(Length, EXACT, "Angstroms", &["ångström"]),
+        Hectare                => details(Area, EXACT, "Hectares", &[]),
+        Acre                   => details(Area, EXACT, "Acres", &[]),
+        Barn                   => details(Area, EXACT, "Barns", &[]),
+        Liter                  => details(Volume, EXACT, "Liters", &["litre"]),
+        UsGallon               => details(Volume, EXACT, "US gallons", &["gallon"]),
+        Quart                  => details(Volume, EXACT, "Quarts", &[]),
+        Pint                   => details(Volume, EXACT, "Pints", &[]),
+        Cup                    => details(Volume, EXACT, "Cups", &[]),
+        FluidOunce             => details(Volume, EXACT, "Fluid ounces", &[]),
+        Tablespoon             => details(Volume, EXACT, "Tablespoons", &[]),
+        Teaspoon               => details(Volume, EXACT, "Teaspoons", &[]),
+        CanadianGallon         => details(Volume, EXACT, "Canadian gallons", &[]),
+        UkGallon               => details(Volume, EXACT, "UK gallons", &["imperial gallon"]),
+        MilesPerHour           => details(Speed, EXACT, "Miles per hour", &[]),
+        KilometersPerHour      => details(Speed, EXACT, "Kilometers per hour", &["kilometres per hour"]),
+        Knot                   => details(Speed, EXACT, "Knots", &[]),
+        Kilogram               => details(Mass, EXACT, "Kilograms", &["kilogramme"]),
+        PoundMass              => details(Mass, EXACT, "Pounds mass", &["pound"]),
+        OunceMass              => details(Mass, EXACT, "Ounces mass", &["ounce"]),
+        Ton                    => details(Mass, EXACT, "Tons", &["short ton"]),
+        MetricTon              => details(Mass, EXACT, "Metric tons", &["tonne"]),
+        UkTon                  => details(Mass, EXACT, "UK tons", &["long ton"]),
+        TroyOunce              => details(Mass, EXACT, "Troy ounces", &[]),
+        Carat                  => details(Mass, EXACT, "Carats", &[]),
+        UnifiedAtomicMass      => details(Mass, MEASURED, "Unified atomic mass units", &["dalton", "atomic mass unit"]),
+        GramForce              => details(Force, EXACT, "Grams force", &[]),
+        PoundForce             => details(Force, EXACT, "Pounds force", &[]),
+        KilopoundForce         => details(Force, EXACT, "Kilopounds force", &["kip"]),
+        Poundal                => details(Force, EXACT, "Poundals", &[]),
+        Calorie                => details(Energy, EXACT, "Calories", &[]),
+        Btu                    => details(Energy, EXACT, "Btus", &["British thermal unit"]),
+        Therm                  => details(Energy, EXACT, "Therms", &[]),
+        Horsepower             => details(Power, EXACT, "Horsepower", &[]),
+        Bar                    => details(Pressure, EXACT, "Bars", &[]),
+        StdAtmosphere          => details(Pressure, EXACT, "STD atmospheres", &["atmosphere", "standard atmosphere"]),
+        Torr                   => details(Pressure, EXACT, "Torr", &[]),
+        MeterOfMercury         => details(Pressure, EXACT, "Meters of mercury", &[]),
+        InchOfMercury          => details(Pressure, EXACT, "Inches of mercury", &[]),
+        InchOfWater            => details(Pressure, EXACT, "Inches of water", &[]),
+        Psi                    => details(Pressure, EXACT, "Psi", &["pound per square inch"]),
+        Poise                  => details(Viscosity, EXACT, "Poise", &[]),
+        Stokes                 => details(Viscosity, EXACT, "Stokes", &[]),
+        Stilb                  => details(Light, EXACT, "Stilbs", &[]),
+        Lumen                  => details(Light, EXACT, "Lumens", &[]),
+        Lux                    => details(Light, EXACT, "Lux", &[]),
+        Phot                   => details(Light, EXACT, "Phots", &[]),
+        Footcandle             => details(Light, EXACT, "Footcandles", &["foot-candle"]),
+        Lambert                => details(Light, EXACT, "Lamberts", &[]),
+        Footlambert            => details(Light, EXACT, "Footlamberts", &["foot-lambert"]),
+        Curie                  => details(Radiation, EXACT, "Curies", &[]),
+        Roentgen               => details(Radiation, EXACT, "Roentgens", &[]),
+        Rad                    => details(Radiation, EXACT, "Rads", &[]),
+        SolarMass              => details(Mass, MEASURED, "Solar masses", &[]),
+        BohrRadius             => details(Constant, MEASURED, "Bohr radius", &[]),
+        Newton                 => details(Force, EXACT, "Newtons", &[]),
+        Dyne                   => details(Force, EXACT, "Dynes", &[]),
+        Joule                  => details(Energy, EXACT, "Joules", &[]),
+        Erg                    => details(Energy, EXACT, "Ergs", &[]),
+        StefanBolzmannConstant => details(Constant, MEASURED, "Stefan-Boltzmann constant", &[]),
+        ThomsonCrossSection    => details(Constant, MEASURED, "Thomson cross section", &[]),
+        BohrMagneton           => details(Constant, MEASURED, "Bohr magneton", &[]),
+        NuclearMagneton        => details(Constant, MEASURED, "Nuclear magneton", &[]),
+        ElectronMagneticMoment => details(Constant, MEASURED, "Electron magnetic moment", &[]),
+        ProtonMagneticMoment   => details(Constant, MEASURED, "Proton magnetic moment", &[]),
+        Faraday                => details(Constant, MEASURED, "Faraday", &["Faraday constant"]),
+        ElectronCharge         => details(Constant, MEASURED, "Electron charge", &["elementary charge"]),
+        VacuumPermittivity     => details(Constant, EXACT, "Vacuum permittivity", &["electric constant"]),
+        VacuumPermeability     => details(Constant, EXACT, "Vacuum permeability", &["magnetic constant"]),
+        Avogadro               => details(Constant, MEASURED, "Avogadro constant", &["Avogadro number", "Avogadro's number"]),
+        FineStructure          => details(Constant, MEASURED, "Fine-structure constant", &["fine structure constant", "alpha"]),
+        Josephson              => details(Constant, MEASURED, "Josephson constant", &[]),
+        VonKlitzing            => details(Constant, MEASURED, "von Klitzing constant", &[]),
+        MagneticFluxQuantum    => details(Constant, MEASURED, "Magnetic flux quantum", &["flux quantum"]),
+        ConductanceQuantum     => details(Constant, MEASURED, "Conductance quantum", &[]),
+        HartreeEnergy          => details(Constant, MEASURED, "Hartree energy", &["hartree"]),
+        ComptonWavelength      => details(Constant, MEASURED, "Compton wavelength", &["electron Compton wavelength"]),
+        ClassicalElectronRadius => details(Constant, MEASURED, "Classical electron radius", &[]),
+        WienDisplacement       => details(Constant, MEASURED, "Wien displacement constant", &["Wien wavelength displacement law constant"]),
+        MassDeuteron           => details(Constant, MEASURED, "Mass of deuteron", &["deuteron mass"]),
+        MassAlpha              => details(Constant, MEASURED, "Mass of alpha particle", &["alpha particle mass"]),
+        PlanckLength           => details(Constant, MEASURED, "Planck length", &[]),
+        PlanckMass             => details(Constant, MEASURED, "Planck mass", &[]),
+        PlanckTime             => details(Constant, MEASURED, "Planck time", &[]),
+        Debye                  => details(Electromagnetic, EXACT, "Debyes", &[]),
+        Gauss                  => details(Electromagnetic, EXACT, "Gauss", &[]),
+    }
+}
+
+/// Source of value of unit name
+const fn name_source(name: Name) -> &'static str {
+    match name {
+        codata_name!() => SOURCE_CODATA,
+        _ => SOURCE_GSL,
+    }
+}
+
+/// Systems of units of unit name
+const fn name_systems(name: Name) -> &'static [UnitSystem] {
+    use Name::*;
+    use UnitSystem::*;
+    match name {
+        Second | Minute | Hour | Day | Meter | Hectare | Liter | Kilogram | MetricTon |
+        Newton | Joule | Lumen | Lux | ElectronVolt | UnifiedAtomicMass | AstronomicalUnit
+            => &[Si],
+        Inch | Foot | Yard | Mile | Fathom | Mil | Acre | PoundMass | OunceMass | TroyOunce |
+        PoundForce | KilopoundForce | Poundal | Btu | Therm | Horsepower | Psi |
+        InchOfMercury | InchOfWater | MilesPerHour | Footcandle | Footlambert
+            => &[UsCustomary, Imperial],
+        UsGallon | Quart | Pint | Cup | FluidOunce | Tablespoon | Teaspoon | Ton
+            => &[UsCustomary],
+        UkGallon | CanadianGallon | UkTon
+            => &[Imperial],
+        Dyne | Erg | Poise | Stokes | Stilb | Phot | Lambert | Gauss | Debye
+            => &[Cgs],
+        _ => &[],
+    }
+}
+
+/// First symbol of unit name in [`SYMBOLS`]
+const fn main_symbol(name: Name) -> Option<&'static str> {
+    let mut i = 0;
+    while i < SYMBOLS.len() {
+        if SYMBOLS[i].1 as usize == name as usize {
+            return Some(SYMBOLS[i].0);
+        }
+        i += 1;
+    }
+    None
+}
+
+impl UnitInfo {
+    const fn new(name: Name, unit: MksUnit, factor: f64, descr: &'static str) -> UnitInfo {
+        let (category, exact, plural, aliases) = name_details(name);
+        UnitInfo {
+            name, unit, factor, descr,
+            symbol: main_symbol(name),
+            plural, aliases, category, exact,
+            systems: name_systems(name),
+            source: name_source(name),
+        }
+    }
+
+    /// Whether `name` is description, plural or alias of the unit, ignoring case
+    pub fn is_named(&self, name: &str) -> bool {
+        std::iter::once(&self.descr).chain(std::iter::once(&self.plural)).chain(self.aliases)
+            .any(|n| n.eq_ignore_ascii_case(name))
+    }
+}
+
+impl Name {
+    /// All names in the list order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// assert_eq!(list::Name::iter().count(), list::UNITS.len());
+    /// assert_eq!(list::Name::iter().next(), Some(list::Name::SpeedOfLight));
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Name> {
+        CATALOGUE.iter().map(|info| info.name)
+    }
+
+    /// Catalogue record of the name
+    pub fn info(self) -> &'static UnitInfo {
+        &CATALOGUE[self as usize]
+    }
+}
+
+/// Find unit by symbol from [`SYMBOLS`], symbols are case sensitive
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// assert_eq!(list::by_symbol("psi").unwrap().name, list::Name::Psi);
+/// assert!(list::by_symbol("PSI").is_none());
+/// ```
+pub fn by_symbol(symbol: &str) -> Option<&'static UnitInfo> {
+    SYMBOLS.iter()
+        .find(|&&(sym, _)| sym == symbol)
+        .map(|&(_, name)| name.info())
+}
+
+/// Find unit by description, plural or alternate name, ignoring case
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// assert_eq!(list::by_name("feet").unwrap().name, list::Name::Foot);
+/// assert_eq!(list::by_name("Tonne").unwrap().name, list::Name::MetricTon);
+/// assert_eq!(list::by_name("electron mass").unwrap().name, list::Name::MassElectron);
+/// ```
+pub fn by_name(name: &str) -> Option<&'static UnitInfo> {
+    CATALOGUE.iter().find(|info| info.is_named(name))
+}
+
+/// Find unit by symbol, or by name if there is no such symbol
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// assert_eq!(list::lookup("atm").unwrap().name, list::Name::StdAtmosphere);
+/// assert_eq!(list::lookup("atmosphere").unwrap().name, list::Name::StdAtmosphere);
+/// ```
+pub fn lookup(s: &str) -> Option<&'static UnitInfo> {
+    by_symbol(s).or_else(|| by_name(s))
+}
+
+/// All units and constants of given dimension
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// let pressure: Vec<_> = list::units_with_dimension(PASCAL_UNIT).map(|info| info.name).collect();
+/// assert!(pressure.contains(&list::Name::Bar) && pressure.contains(&list::Name::Psi));
+/// assert!(!pressure.contains(&list::Name::Joule));
+/// ```
+pub fn units_with_dimension(unit: MksUnit) -> impl Iterator<Item = &'static UnitInfo> {
+    CATALOGUE.iter().filter(move |info| info.unit == unit)
+}
+
+/// All units and constants of given category
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// assert!(list::units_in_category(list::Category::Time).all(|info| info.unit == TIME_UNIT));
+/// ```
+pub fn units_in_category(category: Category) -> impl Iterator<Item = &'static UnitInfo> {
+    CATALOGUE.iter().filter(move |info| info.category == category)
+}
+
+/// Unit found by [`find_units`] with value expressed in it
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitMatch {
+    /// Symbol, or description if the unit has no symbol
+    pub symbol: String,
+    /// Name in the list, `None` for coherent SI unit
+    pub name: Option<Name>,
+    /// Factor to MKS
+    pub factor: f64,
+    /// Value in this unit
+    pub magnitude: f64,
+}
+
+impl UnitMatch {
+    /// How hard the magnitude is to read, lower is better:
+    /// decades outside of `[1, 1000)` plus a tenth for each significant figure.
+    pub fn score(&self) -> f64 {
+        let abs = self.magnitude.abs();
+        if abs == 0.0 || !abs.is_finite() { return 0.0; }
+        let decades = abs.log10();
+        let outside = if decades < 0.0 { -decades } else { (decades - 3.0).max(0.0) };
+        let sig_figs = (1..15)
+            .find(|&n| (format::round_sig(abs, n) - abs).abs() <= abs * 1e-9)
+            .unwrap_or(15);
+        outside + 0.1 * sig_figs as f64
+    }
+}
+
+/// Units of dimension `unit`, from the list and coherent SI unit, ordered by readability
+/// of `val` expressed in them, see [`UnitMatch::score`].
+/// Physical constants are not used as units, `system` restricts the search.
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// use list::UnitSystem;
+/// let power = MksVal::new(0.5, f64::HORSEPOWER, HORSEPOWER_UNIT);
+/// let found = list::find_units(power.val, WATT_UNIT, None);
+/// assert_eq!(found[0].symbol, "hp");
+/// assert_eq!(found[0].magnitude, 0.5);
+/// assert_eq!(found[1].symbol, "W");
+/// let si = list::find_units(power.val, WATT_UNIT, Some(UnitSystem::Si));
+/// assert_eq!(si.len(), 1);
+/// let lengths = list::find_units(5280.0 * f64::FOOT, METER_UNIT, Some(UnitSystem::UsCustomary));
+/// assert_eq!(lengths[0].symbol, "mi");
+/// assert!((lengths[0].magnitude - 1.0).abs() < 1e-12);
+/// ```
+// `Option::is_none_or` needs Rust 1.82
+#[allow(clippy::unnecessary_map_or)]
+pub fn find_units(val: f64, unit: MksUnit, system: Option<UnitSystem>) -> Vec<UnitMatch> {
+    let mut found = Vec::new();
+    if system.is_none() || system == Some(UnitSystem::Si) {
+        found.push(UnitMatch { symbol: unit.as_named_string(), name: None, factor: 1.0, magnitude: val });
+    }
+    found.extend(units_with_dimension(unit)
+        .filter(|info| info.category != Category::Constant)
+        .filter(|info| system.map_or(true, |system| info.systems.contains(&system)))
+        .filter(|info| !found.iter().any(|si| info.factor == 1.0 && Some(si.symbol.as_str()) == info.symbol))
+        .map(|info| UnitMatch {
+            symbol: info.symbol.unwrap_or(info.descr).to_string(),
+            name: Some(info.name),
+            factor: info.factor,
+            magnitude: val / info.factor,
+        })
+        .collect::<Vec<_>>());
+    found.sort_by(|a, b| a.score().total_cmp(&b.score()));
+    found
+}
+
+#[cfg(mks_checked)]
+impl MksVal {
+    /// Most readable unit for the value, see [`find_units`]
+    ///
+    /// Not available in `mks_unchecked` release builds that do not store units.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// let pressure = MksVal::new(2.0, f64::STD_ATMOSPHERE, STD_ATMOSPHERE_UNIT);
+    /// assert_eq!(pressure.best_unit(None).unwrap().symbol, "atm");
+    /// let energy = MksVal::new(3.0e-7, 1.0, JOULE_UNIT);
+    /// assert_eq!(pressure.best_unit(Some(list::UnitSystem::Cgs)), None);
+    /// assert_eq!(energy.best_unit(Some(list::UnitSystem::Cgs)).unwrap().magnitude, 3.0);
+    /// ```
+    pub fn best_unit(&self, system: Option<UnitSystem>) -> Option<UnitMatch> {
+        find_units(self.val, self.unit, system).into_iter().next()
+    }
+}
@@ -0,0 +1,63 @@
+//! Numeric types that can carry MKS values
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+
+use core::ops;
+
+/// Numeric type of [`MksVal`](super::MksVal) value, implemented for `f32` and `f64`.
+///
+/// Implement it for own number types to use them with units of measure.
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// # #[cfg(mks_checked)] {
+/// let len = MksVal::new(6.0_f32, f32::FOOT, FOOT_UNIT);
+/// let g = MksVal::new(1.0_f32, f32::GRAV_ACCEL, GRAV_ACCEL_UNIT);
+/// let period = MksVal::new_scalar(2.0 * std::f32::consts::PI) * (len / g).sqrt();
+/// assert!(period.unit == TIME_UNIT);
+/// assert!((period.val - 2.7133).abs() < 1.0e-3);
+/// # }
+/// ```
+pub trait MksNum:
+    Copy +
+    PartialOrd +
+    ops::Add<Output = Self> +
+    ops::Sub<Output = Self> +
+    ops::Mul<Output = Self> +
+    ops::Div<Output = Self> +
+    ops::Neg<Output = Self>
+{
+    /// Convert from `f64`, possibly losing precision
+    fn from_f64(x: f64) -> Self;
+    /// Convert to `f64`, used for parsing and printing
+    fn to_f64(self) -> f64;
+    /// Square root
+    fn sqrt(self) -> Self;
+    /// Cubic root
+    fn cbrt(self) -> Self;
+    /// Raise to integer power
+    fn powi(self, n: i32) -> Self;
+    /// Raise to real power
+    fn powf(self, x: Self) -> Self;
+}
+
+macro_rules! impl_mks_num {
+    ($($t:ty),*) => {
+        $(
+            impl MksNum for $t {
+                fn from_f64(x: f64) -> Self { x as $t }
+                fn to_f64(self) -> f64 { self.into() }
+                fn sqrt(self) -> Self { <$t>::sqrt(self) }
+                fn cbrt(self) -> Self { <$t>::cbrt(self) }
+                fn powi(self, n: i32) -> Self { <$t>::powi(self, n) }
+                fn powf(self, x: Self) -> Self { <$t>::powf(self, x) }
+            }
+        )*
+    };
+}
+
+impl_mks_num!(f32, f64);
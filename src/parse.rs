@@ -0,0 +1,414 @@
+//! Parsing of unit strings
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+
+use std::fmt;
+use std::str::FromStr;
+use super::*;
+use super::scale::SI_PREFIXES;
+
+/// Kind of unit string parsing error.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseUnitErrorKind {
+    /// Character that can not start or continue unit expression
+    UnexpectedChar(char),
+    /// Symbol that is not a known unit
+    UnknownUnit(String),
+    /// Expected unit symbol, `1` or `(`
+    ExpectedUnit,
+    /// Expected integer or `(n/d)` after `^`
+    ExpectedExponent,
+    /// Expected number at the start of value string
+    ExpectedNumber,
+    /// Missing `)` or `]`
+    UnclosedParen,
+    /// Unit power does not fit
+    Overflow,
+}
+
+/// Error of parsing unit string, with position of bad input.
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// let err = "m / s^x".parse::<MksUnit>().unwrap_err();
+/// assert_eq!(err.pos, 6);
+/// assert_eq!(&err.to_string(), "expected exponent at position 6");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseUnitError {
+    /// Position of bad input, in characters from the start of the string
+    pub pos: usize,
+    /// What is wrong
+    pub kind: ParseUnitErrorKind,
+}
+
+impl fmt::Display for ParseUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ParseUnitErrorKind::UnexpectedChar(c) => write!(f, "unexpected character '{}'", c)?,
+            ParseUnitErrorKind::UnknownUnit(name) => write!(f, "unknown unit '{}'", name)?,
+            ParseUnitErrorKind::ExpectedUnit => write!(f, "expected unit")?,
+            ParseUnitErrorKind::ExpectedNumber => write!(f, "expected number")?,
+            ParseUnitErrorKind::ExpectedExponent => write!(f, "expected exponent")?,
+            ParseUnitErrorKind::UnclosedParen => write!(f, "unclosed parenthesis")?,
+            ParseUnitErrorKind::Overflow => write!(f, "unit power overflow")?,
+        }
+        write!(f, " at position {}", self.pos)
+    }
+}
+
+impl std::error::Error for ParseUnitError {}
+
+/// Base or derived SI unit for symbol.
+pub(crate) fn base_unit(symbol: &str) -> Option<(f64, MksUnit)> {
+    let unit = match symbol {
+        "m"   => METER_UNIT,
+        "kg"  => KILOGRAM_UNIT,
+        "s"   => SECOND_UNIT,
+        "A"   => AMPERE_UNIT,
+        "K"   => KELVIN_UNIT,
+        "mol" => MOLE_UNIT,
+        "cd"  => CANDELA_UNIT,
+        "Hz"  => HERTZ_UNIT,
+        "ohm" => OHM_UNIT,
+        _ => return DERIVED_UNITS.iter()
+            .find(|&&(name, _)| name == symbol)
+            .map(|&(_, unit)| (1.0, unit))
+    };
+    Some((1.0, unit))
+}
+
+/// Unit symbols that take SI prefixes
+const PREFIXABLE: [&str; 28] = [
+    "m", "g", "s", "A", "K", "mol", "cd", "N", "J", "L", "l", "eV", "bar", "cal", "Ci", "Torr", "lm", "lx",
+    "W", "Pa", "Hz", "C", "V", "Ω", "ohm", "F", "H", "Wb",
+];
+
+/// Factor and unit for unit symbol without prefix.
+fn unprefixed_unit(symbol: &str) -> Option<(f64, MksUnit)> {
+    if symbol == "g" {
+        return Some((f64::KILOGRAM / 1000.0, KILOGRAM_UNIT));
+    }
+    base_unit(symbol).or_else(|| {
+        list::SYMBOLS.iter()
+            .find(|&&(sym, _)| sym == symbol)
+            .map(|&(_, name)| {
+                let (_, unit, factor, _) = list::record(name);
+                (factor, unit)
+            })
+    })
+}
+
+/// Factor and unit for unit symbol from [`list::SYMBOLS`] or base SI units,
+/// optionally with SI prefix like `km` or `µs`.
+///
+/// Symbols without prefix are tried first, so `fL` is footlambert, not femtolitre;
+/// use `fl` for femtolitre. `kt` is knot, tonne does not take prefixes.
+pub(crate) fn named_unit(symbol: &str) -> Option<(f64, MksUnit)> {
+    unprefixed_unit(symbol).or_else(|| {
+        SI_PREFIXES.iter().find_map(|&(prefix, scale)| {
+            let base = symbol.strip_prefix(prefix)?;
+            if !PREFIXABLE.contains(&base) { return None; }
+            let (factor, unit) = unprefixed_unit(base)?;
+            Some((scale * factor, unit))
+        })
+    })
+}
+
+/// Recursive descent parser of unit expressions.
+///
+/// Grammar, multiplication binds tighter than division
+/// so `m kg / s^2 K` is `(m kg) / (s^2 K)` as written by [`MksUnit::as_string`]:
+///
+/// ```text
+/// expr     := term ('/' term)*
+/// term     := factor (('*' | '·' | ' ') factor)*
+/// factor   := primary ('^' exponent)?
+/// primary  := symbol | '1' | '(' expr ')'
+/// exponent := ['-'|'+'] int | '(' ['-'|'+'] int ['/' int] ')'
+/// ```
+pub(crate) struct UnitParser<'a, F> {
+    chars: Vec<(usize, char)>,
+    pos: usize,
+    end: usize,
+    resolve: &'a F,
+}
+
+impl<'a, F> UnitParser<'a, F>
+where
+    F: Fn(&str) -> Option<(f64, MksUnit)>
+{
+    /// Parser of `s`, symbols are turned into factor and unit by `resolve`.
+    pub(crate) fn new(s: &str, resolve: &'a F) -> Self {
+        let chars: Vec<(usize, char)> = s.chars().enumerate().collect();
+        let end = chars.len();
+        UnitParser { chars, pos: 0, end, resolve }
+    }
+
+    fn error(&self, kind: ParseUnitErrorKind) -> ParseUnitError {
+        ParseUnitError { pos: self.pos, kind }
+    }
+
+    fn skip_spaces(&mut self) -> bool {
+        let start = self.pos;
+        while self.pos < self.end && self.chars[self.pos].1.is_whitespace() {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).filter(|_| self.pos < self.end).map(|&(_, c)| c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) { self.pos += 1; true } else { false }
+    }
+
+    /// Parse leading floating point number, like `-1.5e3`.
+    pub(crate) fn number(&mut self) -> Result<f64, ParseUnitError> {
+        self.skip_spaces();
+        let start = self.pos;
+        let digits = |p: &mut Self| {
+            let from = p.pos;
+            while matches!(p.peek(), Some(c) if c.is_ascii_digit()) { p.pos += 1; }
+            p.pos - from
+        };
+        if !self.eat('-') { self.eat('+'); }
+        let mut count = digits(self);
+        if self.eat('.') { count += digits(self); }
+        if count == 0 {
+            self.pos = start;
+            return Err(self.error(ParseUnitErrorKind::ExpectedNumber));
+        }
+        let mantissa_end = self.pos;
+        if self.eat('e') || self.eat('E') {
+            if !self.eat('-') { self.eat('+'); }
+            if digits(self) == 0 { self.pos = mantissa_end; }
+        }
+        let text: String = self.chars[start..self.pos].iter().map(|&(_, c)| c).collect();
+        text.parse().map_err(|_| ParseUnitError { pos: start, kind: ParseUnitErrorKind::ExpectedNumber })
+    }
+
+    /// Rest of input after spaces, with its position.
+    pub(crate) fn rest(&mut self) -> (usize, String) {
+        self.skip_spaces();
+        (self.pos, self.chars[self.pos..self.end].iter().map(|&(_, c)| c).collect())
+    }
+
+    /// Parse whole input, allowing it to be enclosed in `[]` like `Display` output.
+    pub(crate) fn parse_all(&mut self) -> Result<(f64, MksUnit), ParseUnitError> {
+        self.skip_spaces();
+        let bracketed = self.eat('[');
+        if bracketed {
+            match self.chars[self.pos..self.end].iter().rposition(|&(_, c)| !c.is_whitespace()) {
+                Some(last) if self.chars[self.pos + last].1 == ']' => self.end = self.pos + last,
+                _ => {
+                    self.pos = self.end;
+                    return Err(self.error(ParseUnitErrorKind::UnclosedParen));
+                }
+            }
+        }
+        self.skip_spaces();
+        // scalar unit is written as empty string
+        let result = if self.peek().is_none() { (1.0, SCALAR_UNIT) } else { self.expr()? };
+        self.skip_spaces();
+        match self.peek() {
+            None => Ok(result),
+            Some(c) => Err(self.error(ParseUnitErrorKind::UnexpectedChar(c))),
+        }
+    }
+
+    fn expr(&mut self) -> Result<(f64, MksUnit), ParseUnitError> {
+        let (mut factor, mut unit) = self.term()?;
+        loop {
+            self.skip_spaces();
+            if !self.eat('/') { break; }
+            let (rhs_factor, rhs_unit) = self.term()?;
+            factor /= rhs_factor;
+            unit = unit.combine(rhs_unit, -1).ok_or_else(|| self.error(ParseUnitErrorKind::Overflow))?;
+        }
+        Ok((factor, unit))
+    }
+
+    fn term(&mut self) -> Result<(f64, MksUnit), ParseUnitError> {
+        let (mut factor, mut unit) = self.factor()?;
+        loop {
+            let start = self.pos;
+            let spaced = self.skip_spaces();
+            let explicit = self.eat('*') || self.eat('·') || self.eat('⋅');
+            if !explicit {
+                let starts_factor = matches!(self.peek(), Some(c) if c == '(' || c == '1' || is_symbol_char(c));
+                if (!spaced && self.peek() != Some('(')) || !starts_factor {
+                    self.pos = start;
+                    break;
+                }
+            }
+            let (rhs_factor, rhs_unit) = self.factor()?;
+            factor *= rhs_factor;
+            unit = unit.combine(rhs_unit, 1).ok_or_else(|| self.error(ParseUnitErrorKind::Overflow))?;
+        }
+        Ok((factor, unit))
+    }
+
+    fn factor(&mut self) -> Result<(f64, MksUnit), ParseUnitError> {
+        let (factor, unit) = self.primary()?;
+        if !self.eat('^') {
+            return Ok((factor, unit));
+        }
+        let start = self.pos;
+        let (num, den) = self.exponent()?;
+        let unit = unit.checked_pow_ratio(num, den)
+            .ok_or(ParseUnitError { pos: start, kind: ParseUnitErrorKind::Overflow })?;
+        let factor = if den == 1 { factor.powi(num.into()) } else { factor.powf(f64::from(num) / f64::from(den)) };
+        Ok((factor, unit))
+    }
+
+    fn primary(&mut self) -> Result<(f64, MksUnit), ParseUnitError> {
+        self.skip_spaces();
+        let start = self.pos;
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let inner = self.expr()?;
+                self.skip_spaces();
+                if !self.eat(')') {
+                    return Err(self.error(ParseUnitErrorKind::UnclosedParen));
+                }
+                Ok(inner)
+            }
+            Some('1') => {
+                self.pos += 1;
+                if matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                    self.pos = start;
+                    return Err(self.error(ParseUnitErrorKind::ExpectedUnit));
+                }
+                Ok((1.0, SCALAR_UNIT))
+            }
+            Some(c) if is_symbol_char(c) => {
+                let symbol: String = self.chars[self.pos..self.end].iter()
+                    .map(|&(_, c)| c)
+                    .take_while(|&c| is_symbol_char(c))
+                    .collect();
+                self.pos += symbol.chars().count();
+                (self.resolve)(&symbol)
+                    .ok_or(ParseUnitError { pos: start, kind: ParseUnitErrorKind::UnknownUnit(symbol) })
+            }
+            Some(c) if c == ')' || c == '/' || c == '*' || c == '^' => Err(self.error(ParseUnitErrorKind::ExpectedUnit)),
+            Some(c) => Err(self.error(ParseUnitErrorKind::UnexpectedChar(c))),
+            None => Err(self.error(ParseUnitErrorKind::ExpectedUnit)),
+        }
+    }
+
+    fn exponent(&mut self) -> Result<(i8, i8), ParseUnitError> {
+        let paren = self.eat('(');
+        let num = self.integer()?;
+        let mut den = 1;
+        if paren {
+            if self.eat('/') {
+                den = self.integer()?;
+                if den == 0 {
+                    self.pos -= 1;
+                    return Err(self.error(ParseUnitErrorKind::ExpectedExponent));
+                }
+            }
+            if !self.eat(')') {
+                return Err(self.error(ParseUnitErrorKind::UnclosedParen));
+            }
+        }
+        Ok((num, den))
+    }
+
+    fn integer(&mut self) -> Result<i8, ParseUnitError> {
+        let start = self.pos;
+        let negative = self.eat('-') || self.eat('−');
+        if !negative { self.eat('+'); }
+        let digits_start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if self.pos == digits_start {
+            return Err(self.error(ParseUnitErrorKind::ExpectedExponent));
+        }
+        let digits: String = self.chars[digits_start..self.pos].iter().map(|&(_, c)| c).collect();
+        let value: i32 = digits.parse()
+            .map_err(|_| ParseUnitError { pos: start, kind: ParseUnitErrorKind::Overflow })?;
+        i8::try_from(if negative { -value } else { value })
+            .map_err(|_| ParseUnitError { pos: start, kind: ParseUnitErrorKind::Overflow })
+    }
+}
+
+/// Whether character can be part of unit symbol.
+pub(crate) fn is_symbol_char(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '°'
+}
+
+impl FromStr for MksUnit {
+    type Err = ParseUnitError;
+
+    /// Parse unit string, inverse of [`MksUnit::as_string`] and `Display`.
+    ///
+    /// Accepts base SI unit symbols `m kg s A K mol cd` and names of derived units
+    /// from [`DERIVED_UNITS`] and `Hz`, combined with
+    /// space, `*` or `·` for multiplication and `/` for division,
+    /// integer powers like `^2` or `^-2` and rational powers like `^(1/2)`,
+    /// parentheses, `1` for unit-less numerator, and optional enclosing `[]`.
+    /// Multiplication binds tighter than division, so `m / s kg` is `m / (s kg)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// assert_eq!("[m / s^2]".parse::<MksUnit>(), Ok(GRAV_ACCEL_UNIT));
+    /// assert_eq!("m*s^-2".parse::<MksUnit>(), Ok(GRAV_ACCEL_UNIT));
+    /// assert_eq!("1/s".parse::<MksUnit>(), Ok(CURIE_UNIT));
+    /// assert_eq!("kg·m^2/(s^2·K)".parse::<MksUnit>(), Ok(BOLTZMANN_UNIT));
+    /// let unit = VACUUM_PERMITTIVITY_UNIT;
+    /// assert_eq!(unit.to_string().parse::<MksUnit>(), Ok(unit));
+    /// assert_eq!(format!("{:#}", unit).parse::<MksUnit>(), Ok(unit));
+    /// assert_eq!("ft".parse::<MksUnit>().unwrap_err().pos, 0);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UnitParser::new(s, &base_unit).parse_all().map(|(_, unit)| unit)
+    }
+}
+
+impl<T: MksNum> FromStr for MksVal<T> {
+    type Err = ParseUnitError;
+
+    /// Parse value string, number followed by unit expression.
+    ///
+    /// Unit expression has the same syntax as for [`MksUnit`],
+    /// symbols are base SI units, `g` for gram and units from [`list::SYMBOLS`],
+    /// SI units may have prefixes from [`Scale`](crate::scale::Scale) like `km` or `µs`.
+    /// Factors of named units are multiplied into the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let len: MksVal = "6 ft".parse().unwrap();
+    /// assert!(len.unit == FOOT_UNIT && len.val == MksVal::new(6.0, f64::FOOT, FOOT_UNIT).val);
+    /// let g: MksVal = "9.80665 m/s^2".parse().unwrap();
+    /// assert!(g.unit == GRAV_ACCEL_UNIT && g.val == f64::GRAV_ACCEL);
+    /// let pressure: MksVal = "30 psi".parse().unwrap();
+    /// assert!(pressure.unit == BAR_UNIT);
+    /// let speed: MksVal = "36 km/h".parse().unwrap();
+    /// assert!((speed.val - 10.0).abs() < 1e-12);
+    /// assert_eq!("6 furlongs".parse::<MksVal>().unwrap_err().pos, 2);
+    /// let len: MksVal<f32> = "6 ft".parse().unwrap();
+    /// assert_eq!(len.val, 6.0 * f32::FOOT);
+    /// # }
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = UnitParser::new(s, &named_unit);
+        let val = parser.number()?;
+        let (factor, unit) = parser.parse_all()?;
+        Ok(MksVal::new(T::from_f64(val), T::from_f64(factor), unit))
+    }
+}
@@ -0,0 +1,464 @@
+//! Quantity with dimension checked at compile time
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+//! [`Quantity`] is a plain `f64` tagged with its dimension type [`Dim`],
+//! powers of the seven SI base units are type-level integers.
+//! Multiplication and division combine dimension types,
+//! addition of quantities with different dimensions does not compile.
+//!
+//! # Example
+//!
+//! ```
+//! use rustamath_mks::*;
+//! use rustamath_mks::quantity::*;
+//! # #[cfg(mks_checked)] {
+//! let pendulum_len = Length::new(6.0 * f64::FOOT);
+//! let g = Acceleration::new(f64::GRAV_ACCEL);
+//! let period: Time = (pendulum_len / g).sqrt() * (2.0 * std::f64::consts::PI);
+//! assert!((period.val - 2.71).abs() < 0.01);
+//! assert_eq!(MksVal::from(period).unit, TIME_UNIT);
+//! # }
+//! ```
+//!
+//! Mismatched dimensions are compile errors:
+//!
+//! ```compile_fail
+//! use rustamath_mks::quantity::*;
+//! let _ = Length::new(1.0) + Time::new(1.0);
+//! ```
+
+use std::marker::PhantomData;
+use std::ops;
+use super::{MksUnit, MksVal, UnitError};
+#[cfg(mks_checked)]
+use super::UnitOp;
+
+/// Type-level integer zero
+#[derive(Debug, Copy, Clone)]
+pub struct Z0;
+
+/// Type-level positive integer `N + 1`, `N` is [`Z0`] or positive
+#[derive(Debug, Copy, Clone)]
+pub struct Succ<N>(PhantomData<N>);
+
+/// Type-level negative integer `N - 1`, `N` is [`Z0`] or negative
+#[derive(Debug, Copy, Clone)]
+pub struct Pred<N>(PhantomData<N>);
+
+/// Type-level 1
+pub type P1 = Succ<Z0>;
+/// Type-level 2
+pub type P2 = Succ<P1>;
+/// Type-level 3
+pub type P3 = Succ<P2>;
+/// Type-level 4
+pub type P4 = Succ<P3>;
+/// Type-level -1
+pub type N1 = Pred<Z0>;
+/// Type-level -2
+pub type N2 = Pred<N1>;
+/// Type-level -3
+pub type N3 = Pred<N2>;
+/// Type-level -4
+pub type N4 = Pred<N3>;
+
+/// Type-level integer, power of a base unit
+pub trait Int {
+    /// Value of the integer
+    const VALUE: i8;
+}
+
+impl Int for Z0 {
+    const VALUE: i8 = 0;
+}
+
+impl<N: Int> Int for Succ<N> {
+    const VALUE: i8 = N::VALUE + 1;
+}
+
+impl<N: Int> Int for Pred<N> {
+    const VALUE: i8 = N::VALUE - 1;
+}
+
+/// Type-level `N + 1`
+pub trait Inc {
+    /// Result
+    type Output: Int;
+}
+
+/// Type-level `N - 1`
+pub trait Dec {
+    /// Result
+    type Output: Int;
+}
+
+/// Type-level `-N`
+pub trait Neg {
+    /// Result
+    type Output: Int;
+}
+
+/// Type-level `N + Rhs`
+pub trait Sum<Rhs> {
+    /// Result
+    type Output: Int;
+}
+
+/// Type-level `N - Rhs`
+pub trait Diff<Rhs> {
+    /// Result
+    type Output: Int;
+}
+
+impl Inc for Z0 { type Output = P1; }
+impl<N: Int> Inc for Succ<N> { type Output = Succ<Succ<N>>; }
+impl<N: Int> Inc for Pred<N> { type Output = N; }
+
+impl Dec for Z0 { type Output = N1; }
+impl<N: Int> Dec for Succ<N> { type Output = N; }
+impl<N: Int> Dec for Pred<N> { type Output = Pred<Pred<N>>; }
+
+impl Neg for Z0 { type Output = Z0; }
+impl<N: Neg> Neg for Succ<N> where N::Output: Dec { type Output = <N::Output as Dec>::Output; }
+impl<N: Neg> Neg for Pred<N> where N::Output: Inc { type Output = <N::Output as Inc>::Output; }
+
+impl<R: Int> Sum<R> for Z0 { type Output = R; }
+impl<N: Sum<R>, R> Sum<R> for Succ<N> where N::Output: Inc { type Output = <N::Output as Inc>::Output; }
+impl<N: Sum<R>, R> Sum<R> for Pred<N> where N::Output: Dec { type Output = <N::Output as Dec>::Output; }
+
+impl<N: Sum<R::Output>, R: Neg> Diff<R> for N { type Output = <N as Sum<R::Output>>::Output; }
+
+/// Dimension as powers of the seven SI base units
+/// (meter, kg, sec, ampere, kelvin, mole, candela).
+#[derive(Debug, Copy, Clone)]
+pub struct Dim<M, K, S, A, KEL, MOL, CD>(PhantomData<(M, K, S, A, KEL, MOL, CD)>);
+
+/// Dimension type with its [`MksUnit`].
+pub trait Dimension {
+    /// Unit of the dimension
+    const UNIT: MksUnit;
+}
+
+impl<M: Int, K: Int, S: Int, A: Int, KEL: Int, MOL: Int, CD: Int> Dimension for Dim<M, K, S, A, KEL, MOL, CD> {
+    const UNIT: MksUnit = MksUnit {
+        m: M::VALUE, k: K::VALUE, s: S::VALUE, a: A::VALUE,
+        kel: KEL::VALUE, mol: MOL::VALUE, cd: CD::VALUE, den: 1
+    };
+}
+
+/// Dimension of product, powers are added.
+pub trait DimMul<Rhs> {
+    /// Result
+    type Output: Dimension;
+}
+
+/// Dimension of quotient, powers are subtracted.
+pub trait DimDiv<Rhs> {
+    /// Result
+    type Output: Dimension;
+}
+
+/// Dimension of square root, all powers must be even.
+pub trait DimSqrt {
+    /// Result
+    type Output: Dimension;
+}
+
+macro_rules! dim_op {
+    ($tr:ident, $op:ident) => {
+        impl<M1, K1, S1, A1, KEL1, MOL1, CD1, M2, K2, S2, A2, KEL2, MOL2, CD2>
+            $tr<Dim<M2, K2, S2, A2, KEL2, MOL2, CD2>> for Dim<M1, K1, S1, A1, KEL1, MOL1, CD1>
+        where
+            M1: $op<M2>, K1: $op<K2>, S1: $op<S2>, A1: $op<A2>,
+            KEL1: $op<KEL2>, MOL1: $op<MOL2>, CD1: $op<CD2>,
+        {
+            type Output = Dim<M1::Output, K1::Output, S1::Output, A1::Output,
+                              KEL1::Output, MOL1::Output, CD1::Output>;
+        }
+    };
+}
+
+dim_op!(DimMul, Sum);
+dim_op!(DimDiv, Diff);
+
+/// Type-level `N / 2` for even `N`
+pub trait Half {
+    /// Result
+    type Output: Int;
+}
+
+impl Half for Z0 { type Output = Z0; }
+impl<N: Int> Half for Succ<Succ<N>> where N: Half { type Output = Succ<N::Output>; }
+impl<N: Int> Half for Pred<Pred<N>> where N: Half { type Output = Pred<N::Output>; }
+
+impl<M: Half, K: Half, S: Half, A: Half, KEL: Half, MOL: Half, CD: Half> DimSqrt for Dim<M, K, S, A, KEL, MOL, CD> {
+    type Output = Dim<M::Output, K::Output, S::Output, A::Output, KEL::Output, MOL::Output, CD::Output>;
+}
+
+/// Value in MKS units with dimension `D` checked at compile time.
+///
+/// Same size as `f64`, the dimension exists only as a type.
+#[repr(transparent)]
+pub struct Quantity<D> {
+    /// Value in MKS units
+    pub val: f64,
+    dim: PhantomData<D>,
+}
+
+impl<D> Quantity<D> {
+    /// Create new quantity from value in MKS units
+    pub const fn new(val: f64) -> Self {
+        Quantity { val, dim: PhantomData }
+    }
+}
+
+impl<D: Dimension> Quantity<D> {
+    /// Unit of the quantity
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::quantity::*;
+    /// assert_eq!(Energy::new(1.0).unit(), JOULE_UNIT);
+    /// ```
+    pub fn unit(&self) -> MksUnit {
+        D::UNIT
+    }
+
+    /// Square root, compiles only if all powers of the dimension are even
+    pub fn sqrt(self) -> Quantity<D::Output> where D: DimSqrt {
+        Quantity::new(self.val.sqrt())
+    }
+}
+
+impl<D> Clone for Quantity<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D> Copy for Quantity<D> {}
+
+impl<D: Dimension> std::fmt::Debug for Quantity<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Quantity({:?} {})", self.val, D::UNIT)
+    }
+}
+
+impl<D: Dimension> std::fmt::Display for Quantity<D> {
+    /// Print same way as [`MksVal`]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&MksVal::from(*self), f)
+    }
+}
+
+impl<D> PartialEq for Quantity<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.val == other.val
+    }
+}
+
+impl<D> PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.val.partial_cmp(&other.val)
+    }
+}
+
+impl<D> ops::Add for Quantity<D> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Quantity::new(self.val + rhs.val)
+    }
+}
+
+impl<D> ops::Sub for Quantity<D> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Quantity::new(self.val - rhs.val)
+    }
+}
+
+impl<D> ops::Neg for Quantity<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Quantity::new(-self.val)
+    }
+}
+
+impl<D1: DimMul<D2>, D2> ops::Mul<Quantity<D2>> for Quantity<D1> {
+    type Output = Quantity<D1::Output>;
+
+    /// Multiply 2 quantities, powers of dimensions are added
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::quantity::*;
+    /// let work: Energy = Force::new(2.0) * Length::new(3.0);
+    /// assert_eq!(work.val, 6.0);
+    /// ```
+    fn mul(self, rhs: Quantity<D2>) -> Self::Output {
+        Quantity::new(self.val * rhs.val)
+    }
+}
+
+impl<D1: DimDiv<D2>, D2> ops::Div<Quantity<D2>> for Quantity<D1> {
+    type Output = Quantity<D1::Output>;
+
+    /// Divide 2 quantities, powers of dimensions are subtracted
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::quantity::*;
+    /// let speed: Velocity = Length::new(100.0) / Time::new(9.58);
+    /// assert!(speed.val > 10.0);
+    /// ```
+    fn div(self, rhs: Quantity<D2>) -> Self::Output {
+        Quantity::new(self.val / rhs.val)
+    }
+}
+
+impl<D> ops::Mul<f64> for Quantity<D> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Quantity::new(self.val * rhs)
+    }
+}
+
+impl<D> ops::Div<f64> for Quantity<D> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Quantity::new(self.val / rhs)
+    }
+}
+
+impl<D: Dimension> From<Quantity<D>> for MksVal {
+    fn from(q: Quantity<D>) -> MksVal {
+        MksVal::new(q.val, 1.0, D::UNIT)
+    }
+}
+
+impl<D: Dimension> TryFrom<MksVal> for Quantity<D> {
+    type Error = UnitError;
+
+    /// Convert value checked at runtime, fail if units do not match
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::quantity::*;
+    /// # #[cfg(mks_checked)] {
+    /// let len = Length::try_from(MksVal::FOOT).unwrap();
+    /// assert_eq!(len.val, f64::FOOT);
+    /// let err = Time::try_from(MksVal::FOOT).unwrap_err();
+    /// assert_eq!(&err.to_string(), "cannot convert [m] to [s]");
+    /// # }
+    /// ```
+    fn try_from(v: MksVal) -> Result<Self, UnitError> {
+        #[cfg(mks_checked)]
+        if v.unit != D::UNIT {
+            return Err(UnitError::binary(UnitOp::Convert, v.unit, D::UNIT));
+        }
+        Ok(Quantity::new(v.val))
+    }
+}
+
+macro_rules! quantities {
+    ($($(#[$doc:meta])* $name:ident = [$m:ty, $k:ty, $s:ty, $a:ty, $kel:ty, $mol:ty, $cd:ty];)*) => {
+        $(
+            $(#[$doc])*
+            pub type $name = Quantity<Dim<$m, $k, $s, $a, $kel, $mol, $cd>>;
+        )*
+    };
+}
+
+quantities! {
+    /// Dimensionless, [`SCALAR_UNIT`](super::SCALAR_UNIT)
+    Dimensionless         = [Z0, Z0, Z0, Z0, Z0, Z0, Z0];
+    /// Length, [`METER_UNIT`](super::METER_UNIT)
+    Length                = [P1, Z0, Z0, Z0, Z0, Z0, Z0];
+    /// Area, [`AREA_UNIT`](super::AREA_UNIT)
+    Area                  = [P2, Z0, Z0, Z0, Z0, Z0, Z0];
+    /// Volume, [`LITER_UNIT`](super::LITER_UNIT)
+    Volume                = [P3, Z0, Z0, Z0, Z0, Z0, Z0];
+    /// Mass, [`KILOGRAM_UNIT`](super::KILOGRAM_UNIT)
+    Mass                  = [Z0, P1, Z0, Z0, Z0, Z0, Z0];
+    /// Time, [`TIME_UNIT`](super::TIME_UNIT)
+    Time                  = [Z0, Z0, P1, Z0, Z0, Z0, Z0];
+    /// Electric current, [`AMPERE_UNIT`](super::AMPERE_UNIT)
+    Current               = [Z0, Z0, Z0, P1, Z0, Z0, Z0];
+    /// Temperature, [`KELVIN_UNIT`](super::KELVIN_UNIT)
+    Temperature           = [Z0, Z0, Z0, Z0, P1, Z0, Z0];
+    /// Amount of substance, [`MOLE_UNIT`](super::MOLE_UNIT)
+    AmountOfSubstance     = [Z0, Z0, Z0, Z0, Z0, P1, Z0];
+    /// Luminous intensity, [`CANDELA_UNIT`](super::CANDELA_UNIT)
+    LuminousIntensity     = [Z0, Z0, Z0, Z0, Z0, Z0, P1];
+    /// Frequency, [`HERTZ_UNIT`](super::HERTZ_UNIT)
+    Frequency             = [Z0, Z0, N1, Z0, Z0, Z0, Z0];
+    /// Velocity, [`VELOCITY_UNIT`](super::VELOCITY_UNIT)
+    Velocity              = [P1, Z0, N1, Z0, Z0, Z0, Z0];
+    /// Acceleration, [`ACCEL_UNIT`](super::ACCEL_UNIT)
+    Acceleration          = [P1, Z0, N2, Z0, Z0, Z0, Z0];
+    /// Force, [`NEWTON_UNIT`](super::NEWTON_UNIT)
+    Force                 = [P1, P1, N2, Z0, Z0, Z0, Z0];
+    /// Energy, [`JOULE_UNIT`](super::JOULE_UNIT)
+    Energy                = [P2, P1, N2, Z0, Z0, Z0, Z0];
+    /// Power, [`WATT_UNIT`](super::WATT_UNIT)
+    Power                 = [P2, P1, N3, Z0, Z0, Z0, Z0];
+    /// Pressure, [`PASCAL_UNIT`](super::PASCAL_UNIT)
+    Pressure              = [N1, P1, N2, Z0, Z0, Z0, Z0];
+    /// Action, [`PLANCKS_CONSTANT_H_UNIT`](super::PLANCKS_CONSTANT_H_UNIT)
+    Action                = [P2, P1, N1, Z0, Z0, Z0, Z0];
+    /// Dynamic viscosity, [`POISE_UNIT`](super::POISE_UNIT)
+    DynamicViscosity      = [N1, P1, N1, Z0, Z0, Z0, Z0];
+    /// Kinematic viscosity, [`STOKES_UNIT`](super::STOKES_UNIT)
+    KinematicViscosity    = [P2, Z0, N1, Z0, Z0, Z0, Z0];
+    /// Absorbed dose, [`RAD_UNIT`](super::RAD_UNIT)
+    AbsorbedDose          = [P2, Z0, N2, Z0, Z0, Z0, Z0];
+    /// Gravitational constant, [`GRAVITATIONAL_CONSTANT_UNIT`](super::GRAVITATIONAL_CONSTANT_UNIT)
+    GravitationalConstant = [P3, N1, N2, Z0, Z0, Z0, Z0];
+    /// Electric charge, [`COULOMB_UNIT`](super::COULOMB_UNIT)
+    Charge                = [Z0, Z0, P1, P1, Z0, Z0, Z0];
+    /// Voltage, [`VOLT_UNIT`](super::VOLT_UNIT)
+    Voltage               = [P2, P1, N3, N1, Z0, Z0, Z0];
+    /// Resistance, [`OHM_UNIT`](super::OHM_UNIT)
+    Resistance            = [P2, P1, N3, N2, Z0, Z0, Z0];
+    /// Capacitance, [`FARAD_UNIT`](super::FARAD_UNIT)
+    Capacitance           = [N2, N1, P4, P2, Z0, Z0, Z0];
+    /// Inductance, [`HENRY_UNIT`](super::HENRY_UNIT)
+    Inductance            = [P2, P1, N2, N2, Z0, Z0, Z0];
+    /// Magnetic flux, [`WEBER_UNIT`](super::WEBER_UNIT)
+    MagneticFlux          = [P2, P1, N2, N1, Z0, Z0, Z0];
+    /// Magnetic flux density, [`TESLA_UNIT`](super::TESLA_UNIT)
+    MagneticFluxDensity   = [Z0, P1, N2, N1, Z0, Z0, Z0];
+    /// Magnetic moment, [`BOHR_MAGNETON_UNIT`](super::BOHR_MAGNETON_UNIT)
+    MagneticMoment        = [P2, Z0, Z0, P1, Z0, Z0, Z0];
+    /// Permittivity, [`VACUUM_PERMITTIVITY_UNIT`](super::VACUUM_PERMITTIVITY_UNIT)
+    Permittivity          = [N3, N1, P4, P2, Z0, Z0, Z0];
+    /// Permeability, [`VACUUM_PERMEABILITY_UNIT`](super::VACUUM_PERMEABILITY_UNIT)
+    Permeability          = [P1, P1, N2, N2, Z0, Z0, Z0];
+    /// Exposure to ionizing radiation, [`ROENTGEN_UNIT`](super::ROENTGEN_UNIT)
+    Exposure              = [Z0, N1, P1, P1, Z0, Z0, Z0];
+    /// Entropy, [`BOLTZMANN_UNIT`](super::BOLTZMANN_UNIT)
+    Entropy               = [P2, P1, N2, Z0, N1, Z0, Z0];
+    /// Molar entropy, [`MOLAR_GAS_UNIT`](super::MOLAR_GAS_UNIT)
+    MolarEntropy          = [P2, P1, N2, Z0, N1, N1, Z0];
+    /// Molar volume, [`STANDARD_GAS_VOLUME_UNIT`](super::STANDARD_GAS_VOLUME_UNIT)
+    MolarVolume           = [P3, Z0, Z0, Z0, Z0, N1, Z0];
+    /// Molar charge, [`FARADAY_UNIT`](super::FARADAY_UNIT)
+    MolarCharge           = [Z0, Z0, P1, P1, Z0, N1, Z0];
+    /// Stefan-Boltzmann constant, [`STEFAN_BOLTZMANN_CONSTANT_UNIT`](super::STEFAN_BOLTZMANN_CONSTANT_UNIT)
+    StefanBoltzmannConstant = [Z0, P1, N3, Z0, N4, Z0, Z0];
+    /// Illuminance and luminance, [`LUX_UNIT`](super::LUX_UNIT)
+    Illuminance           = [N2, Z0, Z0, Z0, Z0, Z0, P1];
+}
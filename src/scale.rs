@@ -5,6 +5,20 @@
 //!
 
 /// Dimensionless scaling factors
+///
+/// Decimal prefixes combine with MKS constants of named units
+/// the same way they prefix unit symbols.
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// use rustamath_mks::scale::{Scale};
+/// let len = MksVal::new(1.5, f64::KILO * f64::METER, METER_UNIT);
+/// assert_eq!(len.val, 1500.0);
+/// assert_eq!("1.5 km".parse::<MksVal>().unwrap().val, len.val);
+/// assert_eq!(f64::CENTI * f64::LITER, "1 cL".parse::<MksVal>().unwrap().val);
+/// ```
 pub trait Scale:
     Copy +
     core::ops::Mul<Output = Self> +
@@ -16,7 +30,7 @@ pub trait Scale:
     /// # Example
     ///
     /// ```
-    /// # use rustamath::constant::scale::{Scale};
+    /// # use rustamath_mks::scale::{Scale};
     /// assert_eq!(2.1f64.scale(f64::MEGA), 2100_000.0_f64)
     /// ```
     fn scale(&self, factor: Self) -> Self {
@@ -25,16 +39,23 @@ pub trait Scale:
 
     /// Divide a number by factor.
     ///
+    /// Named `unscale` so it does not clash with [`Mks::in_units`](super::Mks::in_units)
+    /// when both traits are in scope.
+    ///
     /// # Example
     ///
     /// ```
-    /// # use rustamath::constant::scale::{Scale};
-    /// assert_eq!(2.1f64.scale(f64::MEGA).in_units(f64::KILO), 2100.0_f64)
+    /// # use rustamath_mks::scale::{Scale};
+    /// assert_eq!(2.1f64.scale(f64::MEGA).unscale(f64::KILO), 2100.0_f64)
     /// ```
-    fn in_units(&self, factor: Self) -> Self {
+    fn unscale(&self, factor: Self) -> Self {
         *self / factor
     }
 
+    /// 10^30
+    const QUETTA: Self;
+    /// 10^27
+    const RONNA: Self;
     /// 10^24
     const YOTTA: Self;
     /// 10^21
@@ -54,81 +75,164 @@ pub trait Scale:
     /// # Example
     ///
     /// ```
-    /// # use rustamath::constant::scale::{Scale};
+    /// # use rustamath_mks::scale::{Scale};
     /// assert_eq!(f64::KILO, 1000.0_f64)
     /// ```
     const KILO: Self;
-    /// -3
+    /// 10^2
+    const HECTO: Self;
+    /// 10^1
+    const DECA: Self;
+    /// 10^-1
+    const DECI: Self;
+    /// 10^-2
+    const CENTI: Self;
+    /// 10^-3
     const MILLI: Self;
-    /// -6
+    /// 10^-6
     const MICRO: Self;
-    /// -9
+    /// 10^-9
     const NANO: Self;
-    /// -12
+    /// 10^-12
     const PICO: Self;
-    /// -15
+    /// 10^-15
     const FEMTO: Self;
-    /// -18
+    /// 10^-18
     const ATTO: Self;
-    /// -21
+    /// 10^-21
     const ZEPTO: Self;
-    /// -24
+    /// 10^-24
     const YOCTO: Self;
+    /// 10^-27
+    const RONTO: Self;
+    /// 10^-30
+    const QUECTO: Self;
 
-    /// Kilobyte, 1024 bytes
+    /// 2^10
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath_mks::scale::{Scale};
+    /// assert_eq!(f64::KIBI, 1024.0_f64);
+    /// assert_eq!(f64::GIBI, f64::KIBI * f64::MEBI);
+    /// ```
+    const KIBI: Self;
+    /// 2^20
+    const MEBI: Self;
+    /// 2^30
+    const GIBI: Self;
+    /// 2^40
+    const TEBI: Self;
+    /// 2^50
+    const PEBI: Self;
+    /// 2^60
+    const EXBI: Self;
+    /// 2^70
+    const ZEBI: Self;
+    /// 2^80
+    const YOBI: Self;
+
+    /// Kilobyte, binary (IEC 2^10), 1024 bytes, not SI 1e3, same as [`Scale::KIBI`]
     const KILOBYTE: Self;
-    /// Megabyte
+    /// Megabyte, binary (IEC 2^20), 1024 kilobytes, not SI 1e6, same as [`Scale::MEBI`]
     const MEGABYTE: Self;
-    /// Terabyte
+    /// Gigabyte, binary (IEC 2^30), 1024 megabytes, not SI 1e9, same as [`Scale::GIBI`]
+    const GIGABYTE: Self;
+    /// Terabyte, binary (IEC 2^40), 1024 gigabytes, not SI 1e12, same as [`Scale::TEBI`]
     const TERABYTE: Self;
-    /// Petabyte
+    /// Petabyte, binary (IEC 2^50), 1024 terabytes, not SI 1e15, same as [`Scale::PEBI`]
     const PETABYTE: Self;
+    /// Exabyte, binary (IEC 2^60), 1024 petabytes, not SI 1e18, same as [`Scale::EXBI`]
+    const EXABYTE: Self;
+    /// Zettabyte, binary (IEC 2^70), 1024 exabytes, not SI 1e21, same as [`Scale::ZEBI`]
+    const ZETTABYTE: Self;
+    /// Yottabyte, binary (IEC 2^80), 1024 zettabytes, not SI 1e24, same as [`Scale::YOBI`]
+    const YOTTABYTE: Self;
 }
 
-impl Scale for f64 {
-    const YOTTA: f64 = 1.0e24_f64;
-    const ZETTA: f64 = 1.0e21_f64;
-    const EXA:   f64 = 1.0e18_f64;
-    const PETA:  f64 = 1.0e15_f64;
-    const TERA:  f64 = 1.0e12_f64;
-    const GIGA:  f64 = 1.0e9_f64;
-    const MEGA:  f64 = 1.0e6_f64;
-    const KILO:  f64 = 1.0e3_f64;
-    const MILLI: f64 = 1.0e-3_f64;
-    const MICRO: f64 = 1.0e-6_f64;
-    const NANO:  f64 = 1.0e-9_f64;
-    const PICO:  f64 = 1.0e-12_f64;
-    const FEMTO: f64 = 1.0e-15_f64;
-    const ATTO:  f64 = 1.0e-18_f64;
-    const ZEPTO: f64 = 1.0e-21_f64;
-    const YOCTO: f64 = 1.0e-24_f64;
+/// Implement [`Scale`] for floating point type.
+macro_rules! scale_impl {
+    ($($t:ty),*) => {
+        $(
+            impl Scale for $t {
+                const QUETTA: $t = 1.0e30;
+                const RONNA:  $t = 1.0e27;
+                const YOTTA:  $t = 1.0e24;
+                const ZETTA:  $t = 1.0e21;
+                const EXA:    $t = 1.0e18;
+                const PETA:   $t = 1.0e15;
+                const TERA:   $t = 1.0e12;
+                const GIGA:   $t = 1.0e9;
+                const MEGA:   $t = 1.0e6;
+                const KILO:   $t = 1.0e3;
+                const HECTO:  $t = 1.0e2;
+                const DECA:   $t = 1.0e1;
+                const DECI:   $t = 1.0e-1;
+                const CENTI:  $t = 1.0e-2;
+                const MILLI:  $t = 1.0e-3;
+                const MICRO:  $t = 1.0e-6;
+                const NANO:   $t = 1.0e-9;
+                const PICO:   $t = 1.0e-12;
+                const FEMTO:  $t = 1.0e-15;
+                const ATTO:   $t = 1.0e-18;
+                const ZEPTO:  $t = 1.0e-21;
+                const YOCTO:  $t = 1.0e-24;
+                const RONTO:  $t = 1.0e-27;
+                const QUECTO: $t = 1.0e-30;
 
-    const KILOBYTE: f64 = 1024.0_f64;
-    const MEGABYTE: f64 = Self::KILOBYTE * Self::KILOBYTE;
-    const TERABYTE: f64 = Self::MEGABYTE * Self::KILOBYTE;
-    const PETABYTE: f64 = Self::TERABYTE * Self::KILOBYTE;
+                const KIBI:   $t = (1_u128 << 10) as $t;
+                const MEBI:   $t = (1_u128 << 20) as $t;
+                const GIBI:   $t = (1_u128 << 30) as $t;
+                const TEBI:   $t = (1_u128 << 40) as $t;
+                const PEBI:   $t = (1_u128 << 50) as $t;
+                const EXBI:   $t = (1_u128 << 60) as $t;
+                const ZEBI:   $t = (1_u128 << 70) as $t;
+                const YOBI:   $t = (1_u128 << 80) as $t;
+
+                const KILOBYTE: $t = Self::KIBI;
+                const MEGABYTE: $t = Self::MEBI;
+                const GIGABYTE: $t = Self::GIBI;
+                const TERABYTE: $t = Self::TEBI;
+                const PETABYTE: $t = Self::PEBI;
+                const EXABYTE: $t = Self::EXBI;
+                const ZETTABYTE: $t = Self::ZEBI;
+                const YOTTABYTE: $t = Self::YOBI;
+            }
+        )*
+    };
 }
 
-impl Scale for f32 {
-    const YOTTA: f32 = 1.0e24_f32;
-    const ZETTA: f32 = 1.0e21_f32;
-    const EXA:   f32 = 1.0e18_f32;
-    const PETA:  f32 = 1.0e15_f32;
-    const TERA:  f32 = 1.0e12_f32;
-    const GIGA:  f32 = 1.0e9_f32;
-    const MEGA:  f32 = 1.0e6_f32;
-    const KILO:  f32 = 1.0e3_f32;
-    const MILLI: f32 = 1.0e-3_f32;
-    const MICRO: f32 = 1.0e-6_f32;
-    const NANO:  f32 = 1.0e-9_f32;
-    const PICO:  f32 = 1.0e-12_f32;
-    const FEMTO: f32 = 1.0e-15_f32;
-    const ATTO:  f32 = 1.0e-18_f32;
-    const ZEPTO: f32 = 1.0e-21_f32;
-    const YOCTO: f32 = 1.0e-24_f32;
+scale_impl!(f64, f32);
 
-    const KILOBYTE: f32 = 1024.0_f32;
-    const MEGABYTE: f32 = Self::KILOBYTE * Self::KILOBYTE;
-    const TERABYTE: f32 = Self::MEGABYTE * Self::KILOBYTE;
-    const PETABYTE: f32 = Self::TERABYTE * Self::KILOBYTE;
-}
+/// SI prefix symbols with their factors, from large to small,
+/// `da` goes before `d` so that the longest prefix is tried first;
+/// micro is MICRO SIGN `µ`, with aliases `u` and GREEK SMALL LETTER MU `μ`
+pub(crate) const SI_PREFIXES: [(&str, f64); 26] = [
+    ("Q",  f64::QUETTA),
+    ("R",  f64::RONNA),
+    ("Y",  f64::YOTTA),
+    ("Z",  f64::ZETTA),
+    ("E",  f64::EXA),
+    ("P",  f64::PETA),
+    ("T",  f64::TERA),
+    ("G",  f64::GIGA),
+    ("M",  f64::MEGA),
+    ("k",  f64::KILO),
+    ("h",  f64::HECTO),
+    ("da", f64::DECA),
+    ("d",  f64::DECI),
+    ("c",  f64::CENTI),
+    ("m",  f64::MILLI),
+    ("µ",  f64::MICRO),
+    ("u",  f64::MICRO),
+    ("μ",  f64::MICRO),
+    ("n",  f64::NANO),
+    ("p",  f64::PICO),
+    ("f",  f64::FEMTO),
+    ("a",  f64::ATTO),
+    ("z",  f64::ZEPTO),
+    ("y",  f64::YOCTO),
+    ("r",  f64::RONTO),
+    ("q",  f64::QUECTO),
+];
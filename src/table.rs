@@ -0,0 +1,100 @@
+//! Table of MKS constants, the single source of truth for factors and units
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+//! Each row gives list name, constant name, unit constant name,
+//! powers of the seven SI base units (m, kg, s, A, K, mol, cd), factor and description.
+//! The table is expanded by a callback macro into [`crate::Mks`] constants,
+//! unit constants, [`crate::list::UNITS`] and typed [`crate::MksVal`] constants,
+//! so the factor and the unit of a constant are always written next to each other.
+//!
+//! Rows of physical constants are not typed here: `build.rs` generates them
+//! from NIST CODATA listing in `data/`, with units parsed from the listing.
+
+include!(concat!(env!("OUT_DIR"), "/codata_rows.rs"));
+
+/// Call `$callback!` with all rows of the table.
+macro_rules! mks_table {
+    ($callback:ident) => {
+        codata_rows! { $callback;
+            AstronomicalUnit,       ASTRONOMICAL_UNIT,         ASTRONOMICAL_UNIT_UNIT,         [ 1,  0,  0,  0,  0,  0,  0], 1.49597870691e11_f64,  "Astronomical unit";          // m
+            LightYear,              LIGHT_YEAR,                LIGHT_YEAR_UNIT,                [ 1,  0,  0,  0,  0,  0,  0], 9.46053620707e15_f64,  "Light year";                 // m
+            Parsec,                 PARSEC,                    PARSEC_UNIT,                    [ 1,  0,  0,  0,  0,  0,  0], 3.08567758135e16_f64,  "Parsec";                     // m
+            Second,                 SECOND,                    SECOND_UNIT,                    [ 0,  0,  1,  0,  0,  0,  0], 1.0_f64,               "Second";                     // s
+            Minute,                 MINUTE,                    MINUTE_UNIT,                    [ 0,  0,  1,  0,  0,  0,  0], 6.0e1_f64,             "Minute";                     // s
+            Hour,                   HOUR,                      HOUR_UNIT,                      [ 0,  0,  1,  0,  0,  0,  0], 3.6e3_f64,             "Hour";                       // s
+            Day,                    DAY,                       DAY_UNIT,                       [ 0,  0,  1,  0,  0,  0,  0], 8.64e4_f64,            "Day";                        // s
+            Week,                   WEEK,                      WEEK_UNIT,                      [ 0,  0,  1,  0,  0,  0,  0], 6.048e5_f64,           "Week";                       // s
+            Meter,                  METER,                     METER_UNIT,                     [ 1,  0,  0,  0,  0,  0,  0], 1.0_f64,               "Meter";                      // m
+            Inch,                   INCH,                      INCH_UNIT,                      [ 1,  0,  0,  0,  0,  0,  0], 2.54e-2_f64,           "Inch";                       // m
+            Foot,                   FOOT,                      FOOT_UNIT,                      [ 1,  0,  0,  0,  0,  0,  0], 3.048e-1_f64,          "Foot";                       // m
+            Yard,                   YARD,                      YARD_UNIT,                      [ 1,  0,  0,  0,  0,  0,  0], 9.144e-1_f64,          "Yard";                       // m
+            Mile,                   MILE,                      MILE_UNIT,                      [ 1,  0,  0,  0,  0,  0,  0], 1.609344e3_f64,        "Mile";                       // m
+            NauticalMile,           NAUTICAL_MILE,             NAUTICAL_MILE_UNIT,             [ 1,  0,  0,  0,  0,  0,  0], 1.852e3_f64,           "Nautical mile";              // m
+            Fathom,                 FATHOM,                    FATHOM_UNIT,                    [ 1,  0,  0,  0,  0,  0,  0], 1.8288e0_f64,          "Fathom";                     // m
+            Mil,                    MIL,                       MIL_UNIT,                       [ 1,  0,  0,  0,  0,  0,  0], 2.54e-5_f64,           "Mil";                        // m
+            Point,                  POINT,                     POINT_UNIT,                     [ 1,  0,  0,  0,  0,  0,  0], 3.52777777778e-4_f64,  "Point";                      // m
+            Textpoint,              TEXPOINT,                  TEXPOINT_UNIT,                  [ 1,  0,  0,  0,  0,  0,  0], 3.51459803515e-4_f64,  "Textpoint";                  // m
+            Micron,                 MICRON,                    MICRON_UNIT,                    [ 1,  0,  0,  0,  0,  0,  0], 1e-6_f64,              "Micron";                     // m
+            Angstrom,               ANGSTROM,                  ANGSTROM_UNIT,                  [ 1,  0,  0,  0,  0,  0,  0], 1e-10_f64,             "Angstrom";                   // m
+            Hectare,                HECTARE,                   HECTARE_UNIT,                   [ 2,  0,  0,  0,  0,  0,  0], 1e4_f64,               "Hectare";                    // m^2
+            Acre,                   ACRE,                      ACRE_UNIT,                      [ 2,  0,  0,  0,  0,  0,  0], 4.04685642241e3_f64,   "Acre";                       // m^2
+            Barn,                   BARN,                      BARN_UNIT,                      [ 2,  0,  0,  0,  0,  0,  0], 1e-28_f64,             "Barn";                       // m^2
+            Liter,                  LITER,                     LITER_UNIT,                     [ 3,  0,  0,  0,  0,  0,  0], 1e-3_f64,              "Liter";                      // m^3
+            UsGallon,               US_GALLON,                 US_GALLON_UNIT,                 [ 3,  0,  0,  0,  0,  0,  0], 3.78541178402e-3_f64,  "US gallon";                  // m^3
+            Quart,                  QUART,                     QUART_UNIT,                     [ 3,  0,  0,  0,  0,  0,  0], 9.46352946004e-4_f64,  "Quart";                      // m^3
+            Pint,                   PINT,                      PINT_UNIT,                      [ 3,  0,  0,  0,  0,  0,  0], 4.73176473002e-4_f64,  "Pint";                       // m^3
+            Cup,                    CUP,                       CUP_UNIT,                       [ 3,  0,  0,  0,  0,  0,  0], 2.36588236501e-4_f64,  "Cup";                        // m^3
+            FluidOunce,             FLUID_OUNCE,               FLUID_OUNCE_UNIT,               [ 3,  0,  0,  0,  0,  0,  0], 2.95735295626e-5_f64,  "Fluid ounce";                // m^3
+            Tablespoon,             TABLESPOON,                TABLESPOON_UNIT,                [ 3,  0,  0,  0,  0,  0,  0], 1.47867647813e-5_f64,  "Tablespoon";                 // m^3
+            Teaspoon,               TEASPOON,                  TEASPOON_UNIT,                  [ 3,  0,  0,  0,  0,  0,  0], 4.92892159375e-6_f64,  "Teaspoon";                   // m^3
+            CanadianGallon,         CANADIAN_GALLON,           CANADIAN_GALLON_UNIT,           [ 3,  0,  0,  0,  0,  0,  0], 4.54609e-3_f64,        "Canadian gallon";            // m^3
+            UkGallon,               UK_GALLON,                 UK_GALLON_UNIT,                 [ 3,  0,  0,  0,  0,  0,  0], 4.546092e-3_f64,       "UK gallon";                  // m^3
+            MilesPerHour,           MILES_PER_HOUR,            MILES_PER_HOUR_UNIT,            [ 1,  0, -1,  0,  0,  0,  0], 4.4704e-1_f64,         "Miles per hour";             // m / s
+            KilometersPerHour,      KILOMETERS_PER_HOUR,       KILOMETERS_PER_HOUR_UNIT,       [ 1,  0, -1,  0,  0,  0,  0], 2.77777777778e-1_f64,  "Kilometers per hour";        // m / s
+            Knot,                   KNOT,                      KNOT_UNIT,                      [ 1,  0, -1,  0,  0,  0,  0], 5.14444444444e-1_f64,  "Knot";                       // m / s
+            Kilogram,               KILOGRAM,                  KILOGRAM_UNIT,                  [ 0,  1,  0,  0,  0,  0,  0], 1.0_f64,               "Kilogram";                   // kg
+            PoundMass,              POUND_MASS,                POUND_MASS_UNIT,                [ 0,  1,  0,  0,  0,  0,  0], 4.5359237e-1_f64,      "Pound mass";                 // kg
+            OunceMass,              OUNCE_MASS,                OUNCE_MASS_UNIT,                [ 0,  1,  0,  0,  0,  0,  0], 2.8349523125e-2_f64,   "Ounce mass";                 // kg
+            Ton,                    TON,                       TON_UNIT,                       [ 0,  1,  0,  0,  0,  0,  0], 9.0718474e2_f64,       "Ton";                        // kg
+            MetricTon,              METRIC_TON,                METRIC_TON_UNIT,                [ 0,  1,  0,  0,  0,  0,  0], 1e3_f64,               "Metric ton";                 // kg
+            UkTon,                  UK_TON,                    UK_TON_UNIT,                    [ 0,  1,  0,  0,  0,  0,  0], 1.0160469088e3_f64,    "UK ton";                     // kg
+            TroyOunce,              TROY_OUNCE,                TROY_OUNCE_UNIT,                [ 0,  1,  0,  0,  0,  0,  0], 3.1103475e-2_f64,      "Troy ounce";                 // kg
+            Carat,                  CARAT,                     CARAT_UNIT,                     [ 0,  1,  0,  0,  0,  0,  0], 2e-4_f64,              "Carat";                      // kg
+            GramForce,              GRAM_FORCE,                GRAM_FORCE_UNIT,                [ 1,  1, -2,  0,  0,  0,  0], 9.80665e-3_f64,        "Gram force";                 // kg m / s^2
+            PoundForce,             POUND_FORCE,               POUND_FORCE_UNIT,               [ 1,  1, -2,  0,  0,  0,  0], 4.44822161526e0_f64,   "Pound force";                // kg m / s^2
+            KilopoundForce,         KILOPOUND_FORCE,           KILOPOUND_FORCE_UNIT,           [ 1,  1, -2,  0,  0,  0,  0], 4.44822161526e3_f64,   "Kilopound force";            // kg m / s^2
+            Poundal,                POUNDAL,                   POUNDAL_UNIT,                   [ 1,  1, -2,  0,  0,  0,  0], 1.38255e-1_f64,        "Poundal";                    // kg m / s^2
+            Calorie,                CALORIE,                   CALORIE_UNIT,                   [ 2,  1, -2,  0,  0,  0,  0], 4.1868e0_f64,          "Calorie";                    // kg m^2 / s^2
+            Btu,                    BTU,                       BTU_UNIT,                       [ 2,  1, -2,  0,  0,  0,  0], 1.05505585262e3_f64,   "Btu";                        // kg m^2 / s^2
+            Therm,                  THERM,                     THERM_UNIT,                     [ 2,  1, -2,  0,  0,  0,  0], 1.05506e8_f64,         "Therm";                      // kg m^2 / s^2
+            Horsepower,             HORSEPOWER,                HORSEPOWER_UNIT,                [ 2,  1, -3,  0,  0,  0,  0], 7.457e2_f64,           "Horsepower";                 // kg m^2 / s^3
+            Bar,                    BAR,                       BAR_UNIT,                       [-1,  1, -2,  0,  0,  0,  0], 1e5_f64,               "Bar";                        // kg / m s^2
+            Torr,                   TORR,                      TORR_UNIT,                      [-1,  1, -2,  0,  0,  0,  0], 1.33322368421e2_f64,   "Torr";                       // kg / m s^2
+            MeterOfMercury,         METER_OF_MERCURY,          METER_OF_MERCURY_UNIT,          [-1,  1, -2,  0,  0,  0,  0], 1.33322368421e5_f64,   "Meter of mercury";           // kg / m s^2
+            InchOfMercury,          INCH_OF_MERCURY,           INCH_OF_MERCURY_UNIT,           [-1,  1, -2,  0,  0,  0,  0], 3.38638815789e3_f64,   "Inch of mercury";            // kg / m s^2
+            InchOfWater,            INCH_OF_WATER,             INCH_OF_WATER_UNIT,             [-1,  1, -2,  0,  0,  0,  0], 2.490889e2_f64,        "Inch of water";              // kg / m s^2
+            Psi,                    PSI,                       PSI_UNIT,                       [-1,  1, -2,  0,  0,  0,  0], 6.89475729317e3_f64,   "Psi";                        // kg / m s^2
+            Poise,                  POISE,                     POISE_UNIT,                     [-1,  1, -1,  0,  0,  0,  0], 1e-1_f64,              "Poise";                      // kg m^-1 s^-1
+            Stokes,                 STOKES,                    STOKES_UNIT,                    [ 2,  0, -1,  0,  0,  0,  0], 1e-4_f64,              "Stokes";                     // m^2 / s
+            Stilb,                  STILB,                     STILB_UNIT,                     [-2,  0,  0,  0,  0,  0,  1], 1e4_f64,               "Stilb";                      // cd / m^2
+            Lumen,                  LUMEN,                     LUMEN_UNIT,                     [ 0,  0,  0,  0,  0,  0,  1], 1e0_f64,               "Lumen";                      // cd sr
+            Lux,                    LUX,                       LUX_UNIT,                       [-2,  0,  0,  0,  0,  0,  1], 1e0_f64,               "Lux";                        // cd sr / m^2
+            Phot,                   PHOT,                      PHOT_UNIT,                      [-2,  0,  0,  0,  0,  0,  1], 1e4_f64,               "Phot";                       // cd sr / m^2
+            Footcandle,             FOOTCANDLE,                FOOTCANDLE_UNIT,                [-2,  0,  0,  0,  0,  0,  1], 1.076e1_f64,           "Footcandle";                 // cd sr / m^2
+            Lambert,                LAMBERT,                   LAMBERT_UNIT,                   [-2,  0,  0,  0,  0,  0,  1], 1e4_f64,               "Lambert";                    // cd sr / m^2
+            Footlambert,            FOOTLAMBERT,               FOOTLAMBERT_UNIT,               [-2,  0,  0,  0,  0,  0,  1], 1.07639104e1_f64,      "Footlambert";                // cd sr / m^2
+            Curie,                  CURIE,                     CURIE_UNIT,                     [ 0,  0, -1,  0,  0,  0,  0], 3.7e10_f64,            "Curie";                      // 1 / s
+            Roentgen,               ROENTGEN,                  ROENTGEN_UNIT,                  [ 0, -1,  1,  1,  0,  0,  0], 2.58e-4_f64,           "Roentgen";                   // A s / kg
+            Rad,                    RAD,                       RAD_UNIT,                       [ 2,  0, -2,  0,  0,  0,  0], 1e-2_f64,              "Rad";                        // m^2 / s^2
+            SolarMass,              SOLAR_MASS,                SOLAR_MASS_UNIT,                [ 0,  1,  0,  0,  0,  0,  0], 1.98892e30_f64,        "Solar mass";                 // kg
+            Newton,                 NEWTON,                    NEWTON_UNIT,                    [ 1,  1, -2,  0,  0,  0,  0], 1e0_f64,               "Newton";                     // kg m / s^2
+            Dyne,                   DYNE,                      DYNE_UNIT,                      [ 1,  1, -2,  0,  0,  0,  0], 1e-5_f64,              "Dyne";                       // kg m / s^2
+            Joule,                  JOULE,                     JOULE_UNIT,                     [ 2,  1, -2,  0,  0,  0,  0], 1e0_f64,               "Joule";                      // kg m^2 / s^2
+            Erg,                    ERG,                       ERG_UNIT,                       [ 2,  1, -2,  0,  0,  0,  0], 1e-7_f64,              "Erg";                        // kg m^2 / s^2
+            Debye,                  DEBYE,                     DEBYE_UNIT,                     [ 1,  0,  1,  1,  0,  0,  0], 3.33564095198e-30_f64, "Debye";                      // A s m
+            Gauss,                  GAUSS,                     GAUSS_UNIT,                     [ 0,  1, -2, -1,  0,  0,  0], 1e-4_f64,              "Gauss";                      // kg / A s^2
+        }
+    };
+}
@@ -0,0 +1,299 @@
+//! Temperature scales with offsets
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+//! Celsius and Fahrenheit temperatures have zero away from absolute zero,
+//! so they can not be stored in [`MksVal`] by multiplying with a factor.
+//! [`AbsoluteTemperature`] keeps the temperature in kelvins and converts
+//! to and from [`TemperatureScale`] applying the offset.
+//! Temperature difference is plain [`MksVal`] in kelvins, no offset applies.
+//!
+//! Difference of two temperatures is a difference,
+//! temperature plus difference is a temperature,
+//! sum of two temperatures does not compile.
+//!
+//! # Example
+//!
+//! ```
+//! use rustamath_mks::*;
+//! use rustamath_mks::temperature::*;
+//! # #[cfg(mks_checked)] {
+//! let room = AbsoluteTemperature::new(20.0, TemperatureScale::Celsius);
+//! let body: AbsoluteTemperature = "98.6 °F".parse().unwrap();
+//! let delta = body - room;
+//! assert!(delta.unit == KELVIN_UNIT);
+//! assert!((delta.val - 17.0).abs() < 1e-9);
+//! assert!((TemperatureScale::Fahrenheit.difference_in(delta).unwrap() - 30.6).abs() < 1e-9);
+//! let warmer = room + TemperatureScale::Celsius.difference(10.0);
+//! assert!((warmer.in_scale(TemperatureScale::Celsius) - 30.0).abs() < 1e-9);
+//! # }
+//! ```
+//!
+//! ```compile_fail
+//! use rustamath_mks::temperature::*;
+//! let room = AbsoluteTemperature::new(20.0, TemperatureScale::Celsius);
+//! let _ = room + room;
+//! ```
+
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+use super::{MksVal, UnitError, KELVIN_UNIT, ParseUnitError, ParseUnitErrorKind};
+#[cfg(mks_checked)]
+use super::UnitOp;
+use super::parse::UnitParser;
+
+/// Temperature scale
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TemperatureScale {
+    /// Kelvin, zero is absolute zero
+    Kelvin,
+    /// Degree Celsius, zero is 273.15 K
+    Celsius,
+    /// Degree Fahrenheit, 5/9 K, zero is 459.67 °R
+    Fahrenheit,
+    /// Degree Rankine, 5/9 K, zero is absolute zero
+    Rankine,
+}
+
+/// Symbols accepted by parser for each scale,
+/// bare `C`, `F` and `R` are coulomb, farad and roentgen in unit strings
+const SCALE_SYMBOLS: [(&str, TemperatureScale); 9] = [
+    ("K",    TemperatureScale::Kelvin),
+    ("°C",   TemperatureScale::Celsius),
+    ("℃",    TemperatureScale::Celsius),
+    ("degC", TemperatureScale::Celsius),
+    ("°F",   TemperatureScale::Fahrenheit),
+    ("℉",    TemperatureScale::Fahrenheit),
+    ("degF", TemperatureScale::Fahrenheit),
+    ("°R",   TemperatureScale::Rankine),
+    ("degR", TemperatureScale::Rankine),
+];
+
+impl TemperatureScale {
+    /// Symbol of the scale, like `°C`
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureScale::Kelvin => "K",
+            TemperatureScale::Celsius => "°C",
+            TemperatureScale::Fahrenheit => "°F",
+            TemperatureScale::Rankine => "°R",
+        }
+    }
+
+    /// Size of one degree of the scale in kelvins
+    pub fn degree(&self) -> f64 {
+        match self {
+            TemperatureScale::Kelvin | TemperatureScale::Celsius => 1.0,
+            TemperatureScale::Fahrenheit | TemperatureScale::Rankine => 5.0 / 9.0,
+        }
+    }
+
+    /// Absolute zero in degrees of the scale, negated
+    fn offset(&self) -> f64 {
+        match self {
+            TemperatureScale::Kelvin | TemperatureScale::Rankine => 0.0,
+            TemperatureScale::Celsius => 273.15,
+            TemperatureScale::Fahrenheit => 459.67,
+        }
+    }
+
+    /// Convert temperature on the scale to kelvins, applying the offset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::temperature::*;
+    /// assert_eq!(TemperatureScale::Celsius.to_kelvin(-273.15), 0.0);
+    /// assert!((TemperatureScale::Fahrenheit.to_kelvin(32.0) - 273.15).abs() < 1e-12);
+    /// ```
+    pub fn to_kelvin(&self, t: f64) -> f64 {
+        (t + self.offset()) * self.degree()
+    }
+
+    /// Convert temperature in kelvins to the scale, applying the offset
+    pub fn from_kelvin(&self, kelvin: f64) -> f64 {
+        kelvin / self.degree() - self.offset()
+    }
+
+    /// Temperature difference in degrees of the scale, no offset applies
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::temperature::*;
+    /// # #[cfg(mks_checked)] {
+    /// let delta = TemperatureScale::Fahrenheit.difference(9.0);
+    /// assert!(delta.unit == KELVIN_UNIT);
+    /// assert!((delta.val - 5.0).abs() < 1e-12);
+    /// # }
+    /// ```
+    pub fn difference(&self, degrees: f64) -> MksVal {
+        MksVal::new(degrees, self.degree(), KELVIN_UNIT)
+    }
+
+    /// Temperature difference in degrees of the scale, fail if `delta` is not in kelvins
+    pub fn difference_in(&self, delta: MksVal) -> Result<f64, UnitError> {
+        #[cfg(mks_checked)]
+        if delta.unit != KELVIN_UNIT {
+            return Err(UnitError::binary(UnitOp::Convert, delta.unit, KELVIN_UNIT));
+        }
+        Ok(delta.val / self.degree())
+    }
+}
+
+impl fmt::Display for TemperatureScale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// Temperature measured from absolute zero, stored in kelvins.
+///
+/// # Example
+///
+/// ```
+/// use rustamath_mks::*;
+/// use rustamath_mks::temperature::*;
+/// # #[cfg(mks_checked)] {
+/// let t = AbsoluteTemperature::new(-40.0, TemperatureScale::Fahrenheit);
+/// assert!((t.in_scale(TemperatureScale::Celsius) + 40.0).abs() < 1e-9);
+/// assert_eq!(format!("{:.2}", t), "233.15 K");
+/// let thermal_energy = MksVal::new(1.0, f64::BOLTZMANN, BOLTZMANN_UNIT) * MksVal::from(t);
+/// assert!(thermal_energy.unit == JOULE_UNIT);
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct AbsoluteTemperature {
+    kelvin: f64,
+}
+
+impl AbsoluteTemperature {
+    /// Temperature on the scale
+    pub fn new(t: f64, scale: TemperatureScale) -> Self {
+        AbsoluteTemperature { kelvin: scale.to_kelvin(t) }
+    }
+
+    /// Temperature in kelvins
+    pub fn from_kelvin(kelvin: f64) -> Self {
+        AbsoluteTemperature { kelvin }
+    }
+
+    /// Value in kelvins
+    pub fn kelvin(&self) -> f64 {
+        self.kelvin
+    }
+
+    /// Value on the scale
+    pub fn in_scale(&self, scale: TemperatureScale) -> f64 {
+        scale.from_kelvin(self.kelvin)
+    }
+
+    /// Add temperature difference, fail if `delta` is not in kelvins
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::temperature::*;
+    /// # #[cfg(mks_checked)] {
+    /// let t = AbsoluteTemperature::from_kelvin(300.0);
+    /// assert_eq!(t.try_add(MksVal::new(5.0, 1.0, KELVIN_UNIT)).unwrap().kelvin(), 305.0);
+    /// assert!(t.try_add(MksVal::new(5.0, 1.0, METER_UNIT)).is_err());
+    /// # }
+    /// ```
+    pub fn try_add(&self, delta: MksVal) -> Result<Self, UnitError> {
+        MksVal::from(*self).try_add(delta).map(|t| AbsoluteTemperature::from_kelvin(t.val))
+    }
+
+    /// Subtract temperature difference, fail if `delta` is not in kelvins
+    pub fn try_sub(&self, delta: MksVal) -> Result<Self, UnitError> {
+        MksVal::from(*self).try_sub(delta).map(|t| AbsoluteTemperature::from_kelvin(t.val))
+    }
+}
+
+impl From<AbsoluteTemperature> for MksVal {
+    /// Thermodynamic temperature in kelvins, for use in formulas like `k T`
+    fn from(t: AbsoluteTemperature) -> MksVal {
+        MksVal::new(t.kelvin, 1.0, KELVIN_UNIT)
+    }
+}
+
+impl TryFrom<MksVal> for AbsoluteTemperature {
+    type Error = UnitError;
+
+    /// Temperature from thermodynamic temperature in kelvins, fail if units do not match
+    fn try_from(v: MksVal) -> Result<Self, UnitError> {
+        #[cfg(mks_checked)]
+        if v.unit != KELVIN_UNIT {
+            return Err(UnitError::binary(UnitOp::Convert, v.unit, KELVIN_UNIT));
+        }
+        Ok(AbsoluteTemperature::from_kelvin(v.val))
+    }
+}
+
+impl ops::Sub for AbsoluteTemperature {
+    type Output = MksVal;
+
+    /// Difference of two temperatures in kelvins
+    fn sub(self, rhs: Self) -> MksVal {
+        MksVal::new(self.kelvin - rhs.kelvin, 1.0, KELVIN_UNIT)
+    }
+}
+
+impl ops::Add<MksVal> for AbsoluteTemperature {
+    type Output = Self;
+
+    /// Add temperature difference, the unit mismatch policy applies
+    /// if `delta` is not in kelvins, see [`MismatchPolicy`](super::MismatchPolicy).
+    fn add(self, delta: MksVal) -> Self {
+        AbsoluteTemperature::from_kelvin((MksVal::from(self) + delta).val)
+    }
+}
+
+impl ops::Sub<MksVal> for AbsoluteTemperature {
+    type Output = Self;
+
+    /// Subtract temperature difference, the unit mismatch policy applies
+    /// if `delta` is not in kelvins, see [`MismatchPolicy`](super::MismatchPolicy).
+    fn sub(self, delta: MksVal) -> Self {
+        AbsoluteTemperature::from_kelvin((MksVal::from(self) - delta).val)
+    }
+}
+
+impl fmt::Display for AbsoluteTemperature {
+    /// Print in kelvins same way as [`MksVal`]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&MksVal::from(*self), f)
+    }
+}
+
+impl FromStr for AbsoluteTemperature {
+    type Err = ParseUnitError;
+
+    /// Parse number followed by scale symbol: `K`, `°C`, `°F`, `°R`,
+    /// or `degC`, `degF`, `degR`; bare `C`, `F` and `R` are not temperature scales.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::temperature::*;
+    /// let t: AbsoluteTemperature = "25 °C".parse().unwrap();
+    /// assert_eq!(t.kelvin(), 298.15);
+    /// assert_eq!("9 degR".parse::<AbsoluteTemperature>().unwrap().kelvin(), 5.0);
+    /// assert_eq!("25 °X".parse::<AbsoluteTemperature>().unwrap_err().pos, 3);
+    /// assert!("25 C".parse::<AbsoluteTemperature>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = UnitParser::new(s, &|_| None);
+        let t = parser.number()?;
+        let (pos, symbol) = parser.rest();
+        let symbol = symbol.trim_end();
+        SCALE_SYMBOLS.iter()
+            .find(|&&(sym, _)| sym == symbol)
+            .map(|&(_, scale)| AbsoluteTemperature::new(t, scale))
+            .ok_or_else(|| ParseUnitError { pos, kind: ParseUnitErrorKind::UnknownUnit(symbol.to_string()) })
+    }
+}
@@ -6,14 +6,15 @@
 use super::*;
 
 // cargo test simple_pendulum -- --nocapture
+#[cfg(mks_checked)]
 #[test]
 fn simple_pendulum() {
     // simple pendulum period formula is `T = 2*Pi*sqrt(L/g)`
     let pendulum_len = MksVal::new(6.0, f64::FOOT, FOOT_UNIT);
     let g = MksVal::new(1.0, f64::GRAV_ACCEL, GRAV_ACCEL_UNIT);
 
-    println!("Pendulum length is {:.2} {}", pendulum_len.val, pendulum_len.unit);
-    println!("G on Earth is {:.2} {}", g.val, g.unit);
+    println!("Pendulum length is {:.2}", pendulum_len);
+    println!("G on Earth is {:.2}", g);
 
     assert_eq!(pendulum_len.unit.to_string(), "[m]");
     assert_eq!(g.unit.to_string(), "[m / s^2]");
@@ -26,7 +27,676 @@ fn simple_pendulum() {
     let period = pi_x_2 * pendulum_len_over_accel.sqrt();
     assert!(period.unit == TIME_UNIT);
 
-    println!("Pendulum period is {:.2} {}", period.val, period.unit);
+    println!("Pendulum period is {:.2}", period);
     assert_eq!(period.unit.to_string(), "[s]");
 }
 
+
+#[cfg(mks_checked)]
+#[test]
+fn thermodynamic_units() {
+    // R = k * N_A, so [R] / [k] is [1 / mol]
+    assert_eq!(MOLAR_GAS_UNIT / BOLTZMANN_UNIT, SCALAR_UNIT / MOLE_UNIT);
+    assert_eq!(BOLTZMANN_UNIT * KELVIN_UNIT, JOULE_UNIT);
+    assert_eq!(FARADAY_UNIT * MOLE_UNIT, ELECTRON_CHARGE_UNIT);
+    assert_eq!(STEFAN_BOLTZMANN_CONSTANT_UNIT.to_string(), "[kg / s^3 K^4]");
+    assert_eq!(LUMEN_UNIT.to_string(), "[cd]");
+
+    let k = MksVal::new(1.0, f64::BOLTZMANN, BOLTZMANN_UNIT);
+    let t = MksVal::new(300.0, 1.0, KELVIN_UNIT);
+    assert!((k * t).unit == JOULE_UNIT);
+    assert!(t.pow(2).sqrt().unit == KELVIN_UNIT);
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn checked_arithmetic() {
+    let len = MksVal::new(6.0, f64::FOOT, FOOT_UNIT);
+    let mass = MksVal::new(2.0, f64::POUND_MASS, POUND_MASS_UNIT);
+
+    let err = len.try_add(mass).unwrap_err();
+    assert_eq!(err, UnitError::binary(UnitOp::Add, METER_UNIT, KILOGRAM_UNIT));
+    assert_eq!(len.try_sub(len).unwrap().val, 0.0);
+    assert_eq!(&len.try_sqrt().unwrap_err().to_string(), "cannot take square root of [m]");
+    assert!(len.pow(6).try_cbrt().unwrap().unit == AREA_UNIT);
+
+    let sum = std::panic::catch_unwind(|| len + mass);
+    match MISMATCH_POLICY {
+        MismatchPolicy::Panic => assert!(sum.is_err()),
+        MismatchPolicy::Nan => assert!(sum.unwrap().val.is_nan()),
+        MismatchPolicy::Unchecked => assert!(sum.is_ok()),
+    }
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn roots_reject_odd_powers() {
+    let len = MksVal::new(4.0, f64::METER, METER_UNIT);
+    assert!(len.try_sqrt().is_err());
+    assert!(len.try_root(-1).unwrap().unit == SCALAR_UNIT / METER_UNIT);
+    assert_eq!(MksVal::new_scalar(-8.0).root(3).val, -2.0);
+    assert!(MksVal::new(9.0, 1.0, AREA_UNIT).powf(-0.5).unit == SCALAR_UNIT / METER_UNIT);
+    assert!(len.try_powf(0.5).is_err());
+
+    let root = std::panic::catch_unwind(|| len.sqrt());
+    match MISMATCH_POLICY {
+        MismatchPolicy::Panic => assert!(root.is_err()),
+        MismatchPolicy::Nan => assert!(root.unwrap().val.is_nan()),
+        MismatchPolicy::Unchecked => assert!(root.is_ok()),
+    }
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn fractional_powers() {
+    let sqrt_meter = METER_UNIT.checked_pow_ratio(1, 2).unwrap();
+    assert!(!sqrt_meter.is_integral());
+    assert_eq!(sqrt_meter * sqrt_meter, METER_UNIT);
+    assert!((sqrt_meter * sqrt_meter).is_integral());
+    assert_eq!(sqrt_meter.to_string(), "[m^(1/2)]");
+    assert_eq!((SCALAR_UNIT / sqrt_meter).to_string(), "[1 / m^(1/2)]");
+    let mixed = sqrt_meter * KILOGRAM_UNIT.checked_pow_ratio(-2, 3).unwrap();
+    assert_eq!(mixed.to_string(), "[m^(1/2) / kg^(2/3)]");
+    assert_eq!(mixed.checked_pow(6), Some(LITER_UNIT / KILOGRAM_UNIT.checked_pow(4).unwrap()));
+
+    // strict roots still work for rational powers that divide evenly
+    assert_eq!(mixed.checked_pow(4).unwrap().checked_root(2), mixed.checked_pow(2));
+    assert_eq!(mixed.checked_pow(2).unwrap().checked_root(2), None);
+    assert_eq!(sqrt_meter.checked_root(2), None);
+    assert_eq!(sqrt_meter.checked_powf(2.0), Some(METER_UNIT));
+
+    let len = MksVal::new(9.0, f64::METER, METER_UNIT);
+    let half = len.pow_ratio(1, 2);
+    assert_eq!(half.val, 3.0);
+    assert!((half * half).unit == METER_UNIT);
+}
+
+#[test]
+fn parse_units() {
+    for (_, unit, _, _) in list::UNITS.iter() {
+        assert_eq!(unit.as_string().parse::<MksUnit>(), Ok(*unit));
+        assert_eq!(unit.to_string().parse::<MksUnit>(), Ok(*unit));
+    }
+    let sqrt_meter = METER_UNIT.checked_pow_ratio(1, 2).unwrap();
+    assert_eq!("m^(1/2)".parse::<MksUnit>(), Ok(sqrt_meter));
+    assert_eq!("1 / m^(1/2)".parse::<MksUnit>(), Ok(SCALAR_UNIT / sqrt_meter));
+    assert_eq!("".parse::<MksUnit>(), Ok(SCALAR_UNIT));
+    assert_eq!("[]".parse::<MksUnit>(), Ok(SCALAR_UNIT));
+    assert_eq!("kg m^-1 s^-1".parse::<MksUnit>(), Ok(POISE_UNIT));
+    assert_eq!("m / s kg".parse::<MksUnit>(), "m / (s * kg)".parse::<MksUnit>());
+    assert_eq!("(m/s)^2".parse::<MksUnit>(), Ok(VELOCITY_UNIT * VELOCITY_UNIT));
+
+    let err = |s: &str| s.parse::<MksUnit>().unwrap_err();
+    assert_eq!(err("m / ft"), ParseUnitError { pos: 4, kind: ParseUnitErrorKind::UnknownUnit("ft".to_string()) });
+    assert_eq!(err("(m / s").pos, 6);
+    assert_eq!(err("[m / s").kind, ParseUnitErrorKind::UnclosedParen);
+    assert_eq!(err("m·s⁻²").kind, ParseUnitErrorKind::UnexpectedChar('⁻'));
+    assert_eq!(err("m·s⁻²").pos, 3);
+    assert_eq!(err("m ^ 2"), ParseUnitError { pos: 2, kind: ParseUnitErrorKind::UnexpectedChar('^') });
+    assert_eq!(err("m^(1/0)").pos, 5);
+    assert_eq!(err("m^200").kind, ParseUnitErrorKind::Overflow);
+    assert_eq!(err("m / ").pos, 4);
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn parse_values() {
+    let parse = |s: &str| s.parse::<MksVal>().unwrap();
+    let close = |a: MksVal, b: MksVal| a.unit == b.unit && ((a.val - b.val) / b.val).abs() < 1e-12;
+
+    assert!(close(parse("6 ft"), MksVal::new(6.0, f64::FOOT, FOOT_UNIT)));
+    assert!(close(parse("6ft"), MksVal::new(6.0, f64::FOOT, FOOT_UNIT)));
+    assert!(close(parse("-1.5e3 mi"), MksVal::new(-1.5e3, f64::MILE, MILE_UNIT)));
+    assert!(close(parse("2 BTU"), MksVal::new(2.0, f64::BTU, BTU_UNIT)));
+    assert!(close(parse("1 atm"), MksVal::new(1.0, f64::BAR, BAR_UNIT) * MksVal::new_scalar(1.01325)));
+    assert!(close(parse("5 mg"), MksVal::new(5.0e-6, 1.0, KILOGRAM_UNIT)));
+    assert!(close(parse("3 µs"), MksVal::new(3.0e-6, 1.0, TIME_UNIT)));
+    assert!(close(parse("3 μs"), MksVal::new(3.0e-6, 1.0, TIME_UNIT)));
+    assert!(close(parse("3 us"), MksVal::new(3.0e-6, 1.0, TIME_UNIT)));
+    assert!(close(parse("1 fL"), MksVal::new(1.0, f64::FOOTLAMBERT, FOOTLAMBERT_UNIT)));
+    assert!(close(parse("1 fl"), MksVal::new(1.0e-15, f64::LITER, LITER_UNIT)));
+    assert!(close(parse("1 kt"), MksVal::new(1.0, f64::KNOT, KNOT_UNIT)));
+    assert!(close(parse("7 min"), MksVal::new(7.0, f64::MINUTE, MINUTE_UNIT)));
+    assert!(close(parse("1 kcal"), MksVal::new(1.0e3, f64::CALORIE, CALORIE_UNIT)));
+    assert!(close(parse("4 ft^2"), MksVal::new(4.0, f64::FOOT * f64::FOOT, AREA_UNIT)));
+    assert!(close(parse("1 lbf / in^2"), MksVal::new(1.0, f64::PSI, PSI_UNIT)));
+    assert!(close(parse("2.5 [kg m / s^2]"), MksVal::new(2.5, 1.0, NEWTON_UNIT)));
+    assert!(close(parse("5eV"), MksVal::new(5.0, f64::ELECTRON_VOLT, ELECTRON_VOLT_UNIT)));
+    assert!(parse("42").unit == SCALAR_UNIT);
+
+    let err = |s: &str| s.parse::<MksVal>().unwrap_err();
+    assert_eq!(err("ft"), ParseUnitError { pos: 0, kind: ParseUnitErrorKind::ExpectedNumber });
+    assert_eq!(err("1.2.3 m").kind, ParseUnitErrorKind::UnexpectedChar('.'));
+    assert_eq!(err("3 kft").kind, ParseUnitErrorKind::UnknownUnit("kft".to_string()));
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn format_values() {
+    let prefixed = FormatOptions { si_prefix: true, ..Default::default() };
+    let sig3 = FormatOptions { sig_figs: Some(3), ..prefixed };
+    let eng3 = FormatOptions { engineering: true, sig_figs: Some(3), ..Default::default() };
+
+    let mass = MksVal::new(2.5e-6, f64::KILOGRAM, KILOGRAM_UNIT);
+    assert_eq!(mass.display(prefixed).to_string(), "2.5 mg");
+    assert_eq!(MksVal::new(1.5, 1.0, KILOGRAM_UNIT).display(prefixed).to_string(), "1.5 kg");
+    assert_eq!(MksVal::new(3.0e-6, 1.0, TIME_UNIT).display(prefixed).to_string(), "3 µs");
+    assert_eq!(MksVal::new(0.999_96, 1.0, METER_UNIT).display(sig3).to_string(), "1.00 m");
+    assert_eq!(MksVal::new(12_345.0, 1.0, AREA_UNIT).display(sig3).to_string(), "12300 m^2");
+    assert_eq!(MksVal::new(12_345.0, 1.0, AREA_UNIT).display(eng3).to_string(), "12.3e3 m^2");
+    assert_eq!(MksVal::new(999_600.0, 1.0, AREA_UNIT).display(eng3).to_string(), "1.00e6 m^2");
+    assert_eq!(MksVal::new(-4.2e-7, 1.0, TIME_UNIT).display(prefixed).to_string(), "-420 ns");
+    assert_eq!(format!("{:.3}", MksVal::new(2.0e-3, 1.0, CURIE_UNIT).display(prefixed)), "0.002 1 / s");
+    assert_eq!(format!("{:e}", MksVal::new(1500.0, 1.0, JOULE_UNIT)), "1.5e3 m^2 kg / s^2");
+    assert_eq!(format!("{:.2E}", MksVal::new(1500.0, 1.0, JOULE_UNIT)), "1.50E3 m^2 kg / s^2");
+
+    // printed values can be parsed back
+    let g = MksVal::new(1.0, f64::GRAV_ACCEL, GRAV_ACCEL_UNIT);
+    assert_eq!(g.to_string().parse::<MksVal>().unwrap().val, g.val);
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn derived_unit_names() {
+    assert_eq!(WATT_UNIT.as_named_string(), "W");
+    assert_eq!(HORSEPOWER_UNIT.as_named_string(), "W");
+    assert_eq!(PSI_UNIT.as_named_string(), "Pa");
+    assert_eq!(ELECTRON_CHARGE_UNIT.as_named_string(), "C");
+    assert_eq!(GAUSS_UNIT.as_named_string(), "T");
+    assert_eq!(MOLAR_GAS_UNIT.as_named_string(), "J / K mol");
+    assert_eq!(FARADAY_UNIT.as_named_string(), "C / mol");
+    assert_eq!((VOLT_UNIT / METER_UNIT).as_named_string(), "V / m");
+    assert_eq!((OHM_UNIT * METER_UNIT).as_named_string(), "Ω m");
+    // nothing simpler in named units
+    assert_eq!(GRAV_ACCEL_UNIT.as_named_string(), "m / s^2");
+    assert_eq!(BOHR_MAGNETON_UNIT.as_named_string(), "m^2 A");
+    assert_eq!(SCALAR_UNIT.as_named_string(), "");
+
+    for (_, unit, _, _) in list::UNITS.iter() {
+        assert_eq!(unit.as_named_string().parse::<MksUnit>(), Ok(*unit));
+    }
+    assert!(close_to("2 kPa", 2.0e3, PASCAL_UNIT));
+    assert!(close_to("5 mV", 5.0e-3, VOLT_UNIT));
+    assert!(close_to("10 kohm", 1.0e4, OHM_UNIT));
+    assert!(close_to("3 GHz", 3.0e9, HERTZ_UNIT));
+}
+
+#[cfg(mks_checked)]
+fn close_to(s: &str, val: f64, unit: MksUnit) -> bool {
+    let v = s.parse::<MksVal>().unwrap();
+    v.unit == unit && ((v.val - val) / val).abs() < 1e-12
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn format_styles() {
+    let opts = |style, si_prefix, engineering| FormatOptions { style, si_prefix, engineering, ..Default::default() };
+    let thickness = MksVal::new(1.83e-6, f64::METER, METER_UNIT);
+    let power = MksVal::new(2.5e4, f64::JOULE, JOULE_UNIT) / MksVal::new(1.0, f64::SECOND, SECOND_UNIT);
+
+    assert_eq!(thickness.display(opts(UnitStyle::Siunitx, true, false)).to_string(), r"\SI{1.83}{\micro\metre}");
+    assert_eq!(thickness.display(opts(UnitStyle::Latex, true, false)).to_string(), r"1.83\,\mathrm{\mu m}");
+    assert_eq!(thickness.display(opts(UnitStyle::Unicode, false, true)).to_string(), "1.83×10⁻⁶ m");
+    assert_eq!(thickness.display(opts(UnitStyle::Html, false, true)).to_string(), "1.83&times;10<sup>-6</sup> m");
+    assert_eq!(thickness.display(opts(UnitStyle::Latex, false, true)).to_string(), r"1.83\times10^{-6}\,\mathrm{m}");
+    assert_eq!(power.display(opts(UnitStyle::Unicode, false, false)).to_string(), "25000 m²·kg·s⁻³");
+    assert_eq!(power.display(FormatOptions { derived_names: true, ..opts(UnitStyle::Siunitx, true, false) }).to_string(),
+        r"\SI{25}{\kilo\watt}");
+    assert_eq!(MksVal::new_scalar(3.0).display(opts(UnitStyle::Siunitx, false, false)).to_string(), r"\num{3}");
+    let tiny = MksVal::new(2.0e-29, f64::METER, METER_UNIT);
+    assert_eq!(format!("{:.0}", tiny.display(opts(UnitStyle::Siunitx, true, false))), r"\SI{20}{\quecto\metre}");
+    let huge = MksVal::new(3.0e28, f64::KILOGRAM, KILOGRAM_UNIT);
+    assert_eq!(format!("{:.0}", huge.display(opts(UnitStyle::Siunitx, true, false))), r"\SI{30}{\quetta\gram}");
+
+    let style = |style| FormatOptions { style, ..Default::default() };
+    assert_eq!(VACUUM_PERMITTIVITY_UNIT.display(style(UnitStyle::Html)).to_string(), "s<sup>4</sup> A<sup>2</sup> / m<sup>3</sup> kg");
+    assert_eq!(BOLTZMANN_UNIT.display(style(UnitStyle::Siunitx)).to_string(),
+        r"\si{\metre\squared\kilogram\per\second\squared\per\kelvin}");
+    let named = FormatOptions { derived_names: true, style: UnitStyle::Latex, ..Default::default() };
+    assert_eq!((OHM_UNIT * METER_UNIT).display(named).to_string(), r"\mathrm{\Omega}\,\mathrm{m}");
+    let noise = VOLT_UNIT / HERTZ_UNIT.checked_pow_ratio(1, 2).unwrap();
+    assert_eq!(noise.display(FormatOptions { derived_names: true, ..style(UnitStyle::Unicode) }).to_string(), "V·s¹ᐟ²");
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn typed_constants() {
+    for (i, &(name, unit, factor, _)) in list::UNITS.iter().enumerate() {
+        assert_eq!(name as usize, i);
+        assert!(list::record(name).1 == unit && list::record(name).2 == factor);
+    }
+    let (_, unit, factor, descr) = list::record(list::Name::VacuumPermeability);
+    assert!(unit == VACUUM_PERMEABILITY_UNIT && factor == f64::VACUUM_PERMEABILITY);
+    assert_eq!(descr, "Vacuum permeability");
+
+    assert_eq!(PLANCKS_CONSTANT_H_UNIT, JOULE_UNIT * SECOND_UNIT);
+    assert_eq!(PLANCKS_CONSTANT_HBAR_UNIT, JOULE_UNIT * SECOND_UNIT);
+    // electric dipole moment, C m
+    assert_eq!(DEBYE_UNIT, ELECTRON_CHARGE_UNIT * METER_UNIT);
+    assert_eq!("2 D".parse::<MksVal>().unwrap().unit, ELECTRON_CHARGE_UNIT * METER_UNIT);
+
+    // c^2 = 1 / (mu0 eps0)
+    let c = MksVal::SPEED_OF_LIGHT;
+    let one = c * c * MksVal::VACUUM_PERMEABILITY * MksVal::VACUUM_PERMITTIVITY;
+    assert!(one.unit == SCALAR_UNIT);
+    assert!((one.val - 1.0).abs() < 1e-9);
+
+    let len = MksVal::new_scalar(6.0) * MksVal::FOOT;
+    assert!(len.unit == FOOT_UNIT && len.val == 6.0 * f64::FOOT);
+    assert!((MksVal::PLANCKS_CONSTANT_H / MksVal::PLANCKS_CONSTANT_HBAR).unit == SCALAR_UNIT);
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn static_quantities() {
+    use quantity::*;
+    assert_eq!(Length::new(1.0).unit(), METER_UNIT);
+    assert_eq!(Velocity::new(1.0).unit(), VELOCITY_UNIT);
+    assert_eq!(Energy::new(1.0).unit(), JOULE_UNIT);
+    assert_eq!(Action::new(1.0).unit(), PLANCKS_CONSTANT_H_UNIT);
+    assert_eq!(Capacitance::new(1.0).unit(), FARAD_UNIT);
+    assert_eq!(Permittivity::new(1.0).unit(), VACUUM_PERMITTIVITY_UNIT);
+    assert_eq!(StefanBoltzmannConstant::new(1.0).unit(), STEFAN_BOLTZMANN_CONSTANT_UNIT);
+    assert_eq!(std::mem::size_of::<Energy>(), std::mem::size_of::<f64>());
+
+    let mass = Mass::try_from(MksVal::new_scalar(2.0) * MksVal::POUND_MASS).unwrap();
+    let c = Velocity::try_from(MksVal::SPEED_OF_LIGHT).unwrap();
+    let energy: Energy = mass * c * c;
+    let power: Power = energy / Time::new(f64::HOUR);
+    let voltage: Voltage = power / Current::new(10.0);
+    let resistance: Resistance = voltage / Current::new(10.0);
+    assert!(MksVal::from(resistance).unit == OHM_UNIT);
+    assert_eq!(resistance.val, energy.val / f64::HOUR / 100.0);
+
+    let side: Length = Area::new(9.0).sqrt();
+    assert_eq!(side + Length::new(1.0), Length::new(4.0));
+    assert!(-side < side);
+    assert!(Area::try_from(MksVal::FOOT).is_err());
+    assert_eq!(format!("{:.1}", Length::new(1.5)), "1.5 m");
+}
+
+// RUSTFLAGS="--cfg mks_unchecked" RUSTDOCFLAGS="--cfg mks_unchecked" cargo test --release
+#[test]
+fn values_in_any_build() {
+    let pendulum_len = MksVal::new(6.0, f64::FOOT, FOOT_UNIT);
+    let period = MksVal::new_scalar(2.0 * std::f64::consts::PI) * (pendulum_len / MksVal::GRAV_ACCEL).sqrt();
+    assert!((period.val - 2.71).abs() < 0.01);
+    assert_eq!((pendulum_len + pendulum_len - pendulum_len).val, pendulum_len.val);
+    assert!((pendulum_len.pow(3).cbrt().val - pendulum_len.val).abs() < 1e-12);
+    assert_eq!(MksVal::new_scalar(-8.0).root(3).val, -2.0);
+    assert_eq!("6 ft".parse::<MksVal>().unwrap().val, pendulum_len.val);
+
+    let sum = std::panic::catch_unwind(|| pendulum_len + MksVal::SECOND);
+    if cfg!(mks_checked) {
+        assert!(std::mem::size_of::<MksVal>() > std::mem::size_of::<f64>());
+    }
+    else {
+        assert_eq!(MISMATCH_POLICY, MismatchPolicy::Unchecked);
+        assert_eq!(std::mem::size_of::<MksVal>(), std::mem::size_of::<f64>());
+        assert_eq!(format!("{:.2}", period), "2.71");
+        assert!(sum.is_ok());
+    }
+}
+
+#[test]
+fn f32_values() {
+    let pendulum_len: MksVal<f32> = MksVal::new(6.0, f32::FOOT, FOOT_UNIT);
+    let g = MksVal::new(1.0, f32::GRAV_ACCEL, GRAV_ACCEL_UNIT);
+    let period = MksVal::new_scalar(2.0 * std::f32::consts::PI) * (pendulum_len / g).sqrt();
+    assert!((period.val - 2.71).abs() < 0.01);
+    assert!(format!("{:.2}", period).starts_with("2.71"));
+    assert_eq!(MksVal::new_scalar(-8.0_f32).root(3).val, -2.0);
+    assert_eq!("6 ft".parse::<MksVal<f32>>().unwrap().val, pendulum_len.val);
+    assert_eq!(f32::SPEED_OF_LIGHT, f64::SPEED_OF_LIGHT as f32);
+    if cfg!(mks_checked) {
+        assert!(MksVal::new_scalar(4.0_f32).try_pow_ratio(1, 0).is_err());
+    }
+    else {
+        assert_eq!(std::mem::size_of::<MksVal<f32>>(), std::mem::size_of::<f32>());
+    }
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn scale_prefixes() {
+    use scale::Scale;
+    assert_eq!("3 hPa".parse::<MksVal>().unwrap().val, 3.0 * f64::HECTO);
+    assert_eq!("2 dam".parse::<MksVal>().unwrap().val, 20.0);
+    assert!("1 cd".parse::<MksVal>().unwrap().unit == CANDELA_UNIT);
+    assert!((("5 cm".parse::<MksVal>().unwrap().val) - 0.05).abs() < 1e-15);
+    assert_eq!("1 Qg".parse::<MksVal>().unwrap().val, f64::RONNA);
+    assert_eq!("1 rs".parse::<MksVal>().unwrap().val, f64::RONTO);
+    let prefixed = FormatOptions { si_prefix: true, ..Default::default() };
+    assert_eq!(format!("{}", MksVal::new(0.05, 1.0, METER_UNIT).display(prefixed)), "50 mm");
+    assert_eq!(format!("{}", MksVal::new(2.0, f64::RONNA, METER_UNIT).display(prefixed)), "2 Rm");
+    assert_eq!(f64::YOTTABYTE, f64::ZETTABYTE * f64::KIBI);
+    assert_eq!(f32::GIGABYTE, 1_073_741_824.0);
+    // `Mks` and `Scale` are both in scope
+    assert_eq!(3.0_f64.to_units(f64::FOOT).in_units(f64::FOOT), 3.0);
+    assert_eq!(3.0_f64.scale(f64::KILO).unscale(f64::KILO), 3.0);
+}
+
+#[test]
+fn unit_conversion() {
+    use list::Name;
+    assert_eq!(list::conversion_factor(Name::Day, Name::Hour), Ok(24.0));
+    assert_eq!(list::convert(2.0, Name::Mile, Name::Mile), Ok(2.0));
+    assert!((list::convert(1.0, Name::StdAtmosphere, Name::Torr).unwrap() - 760.0).abs() < 1e-9);
+    let err = list::convert(1.0, Name::Foot, Name::Kilogram).unwrap_err();
+    assert_eq!(err, UnitError::binary(UnitOp::Convert, METER_UNIT, KILOGRAM_UNIT));
+
+    let len = MksVal::new(3.0, f64::FOOT, FOOT_UNIT);
+    assert!((len.in_unit(Name::Yard).unwrap() - 1.0).abs() < 1e-12);
+    if cfg!(mks_checked) {
+        assert!(len.in_unit(Name::Second).is_err());
+    }
+}
+
+#[test]
+fn unit_catalogue() {
+    use list::{Category, Name};
+    let names: std::collections::HashSet<Name> = Name::iter().collect();
+    assert_eq!(names.len(), list::CATALOGUE.len());
+    for (i, info) in list::CATALOGUE.iter().enumerate() {
+        assert_eq!(info.name as usize, i);
+        assert_eq!(list::by_name(info.descr).map(|found| found.factor), Some(info.factor));
+        if let Some(symbol) = info.symbol {
+            assert_eq!(list::by_symbol(symbol).unwrap().name, info.name);
+        }
+    }
+    for (category, unit) in [(Category::Length, METER_UNIT), (Category::Pressure, PASCAL_UNIT),
+                             (Category::Energy, JOULE_UNIT), (Category::Mass, KILOGRAM_UNIT)] {
+        assert!(list::units_in_category(category).all(|info| info.unit == unit));
+    }
+    assert!(list::units_with_dimension(PASCAL_UNIT).count() == 7);
+    assert!(!Name::GravitationalConstant.info().exact);
+    assert_eq!(Name::Knot.info().symbol, Some("kn"));
+    assert_eq!(Name::Rydberg.info().symbol, None);
+    assert!(list::lookup("furlong").is_none());
+}
+
+#[test]
+fn temperature_scales() {
+    use temperature::{AbsoluteTemperature, TemperatureScale};
+    let freezing = AbsoluteTemperature::new(32.0, TemperatureScale::Fahrenheit);
+    assert!((freezing.in_scale(TemperatureScale::Celsius)).abs() < 1e-12);
+    assert!((freezing.in_scale(TemperatureScale::Rankine) - 491.67).abs() < 1e-12);
+    let boiling: AbsoluteTemperature = "100°C".parse().unwrap();
+    assert!((boiling.in_scale(TemperatureScale::Fahrenheit) - 212.0).abs() < 1e-9);
+
+    // 100 °C - 0 °C is 100 K, not 373.15 K
+    let delta = boiling - freezing;
+    assert!((delta.val - 100.0).abs() < 1e-9);
+    assert!((TemperatureScale::Fahrenheit.difference_in(delta).unwrap() - 180.0).abs() < 1e-9);
+    assert!(((boiling - delta) - freezing).val.abs() < 1e-9);
+    assert!(boiling > freezing);
+    assert_eq!("20 degF".parse::<AbsoluteTemperature>().unwrap(), AbsoluteTemperature::new(20.0, TemperatureScale::Fahrenheit));
+    assert_eq!("x °C".parse::<AbsoluteTemperature>().unwrap_err().kind, ParseUnitErrorKind::ExpectedNumber);
+    for symbol in ["C", "F", "R"] {
+        let err = format!("20 {}", symbol).parse::<AbsoluteTemperature>().unwrap_err();
+        assert_eq!(err.kind, ParseUnitErrorKind::UnknownUnit(symbol.to_string()));
+    }
+    if cfg!(mks_checked) {
+        assert!(freezing.try_sub(MksVal::SECOND).is_err());
+        assert!(TemperatureScale::Celsius.difference_in(MksVal::SECOND).is_err());
+        assert!(AbsoluteTemperature::try_from(MksVal::SECOND).is_err());
+    }
+}
+
+#[test]
+fn affine_points() {
+    use affine::{Point, SpaceOrigin, UnixEpoch, AbsoluteZero, CelsiusZero};
+    use temperature::{AbsoluteTemperature, TemperatureScale};
+    let a: Point<SpaceOrigin> = Point::new(MksVal::new(3.0, f64::FOOT, FOOT_UNIT));
+    let b = a + MksVal::new(1.0, f64::YARD, YARD_UNIT);
+    assert!(((b - a).val - f64::YARD).abs() < 1e-12);
+    assert!(b > a && a > Point::origin());
+    assert!((MksVal::new(2.0, 1.0, METER_UNIT) + a - MksVal::new(2.0, 1.0, METER_UNIT)) == a);
+
+    let t: Point<UnixEpoch, f32> = Point::new(MksVal::new(1.0, f32::DAY, DAY_UNIT));
+    assert_eq!((t - Point::origin()).val, f32::DAY);
+
+    let room = AbsoluteTemperature::new(20.0, TemperatureScale::Celsius);
+    let p = Point::<AbsoluteZero>::from(room).to_origin::<CelsiusZero>().unwrap();
+    assert!((p.from_origin().val - 20.0).abs() < 1e-9);
+    assert_eq!(AbsoluteTemperature::from(p.to_origin::<AbsoluteZero>().unwrap()), room);
+    assert!(a.to_origin::<UnixEpoch>().is_err());
+    if cfg!(mks_checked) {
+        assert!(a.try_add(MksVal::SECOND).is_err());
+        assert!(Point::<UnixEpoch>::try_new(MksVal::FOOT).is_err());
+    }
+}
+
+#[cfg(mks_checked)]
+#[test]
+fn equivalencies() {
+    use equivalency::*;
+    let red: MksVal = "700 nm".parse().unwrap();
+    let freq = red.convert_with(HERTZ_UNIT, &SPECTRAL).unwrap();
+    assert!((freq.val / 4.283e14 - 1.0).abs() < 1e-3);
+    let back = freq.convert_with(DISTANCE_UNIT, &SPECTRAL).unwrap();
+    assert!((back.val - red.val).abs() < 1e-18);
+    let wavenumber = red.convert_with(SCALAR_UNIT / METER_UNIT, &SPECTRAL).unwrap();
+    let energy = wavenumber.convert_with(JOULE_UNIT, &SPECTRAL).unwrap();
+    assert!((energy.in_unit(list::Name::ElectronVolt).unwrap() - 1.771).abs() < 1e-3);
+
+    let room = MksVal::new(300.0_f64, 1.0, KELVIN_UNIT);
+    let kt = room.convert_with(JOULE_UNIT, &TEMPERATURE_ENERGY).unwrap();
+    assert!((kt.in_unit(list::Name::ElectronVolt).unwrap() - 0.02585).abs() < 1e-4);
+    assert!(room.convert_with(KELVIN_UNIT, &SPECTRAL).unwrap().val == room.val);
+    assert!(room.convert_with(JOULE_UNIT, &SPECTRAL).is_err());
+    assert!(room.in_unit(list::Name::Joule).is_err());
+
+    let proxima = MksVal::new(1.3020, f64::PARSEC, PARSEC_UNIT);
+    let parallax = proxima.convert_with(SCALAR_UNIT, &PARALLAX).unwrap();
+    assert!((parallax.val * 648_000.0 / std::f64::consts::PI - 0.768).abs() < 1e-3);
+}
+
+#[test]
+fn reverse_unit_lookup() {
+    use list::{find_units, Name, UnitSystem};
+    let power = 0.5 * f64::HORSEPOWER;
+    let found = find_units(power, WATT_UNIT, None);
+    assert_eq!(found[0].name, Some(Name::Horsepower));
+    assert!(found.iter().any(|m| m.name.is_none() && m.symbol == "W"));
+    assert!(found.iter().all(|m| (m.magnitude * m.factor - power).abs() < 1e-9));
+    assert!(found.windows(2).all(|w| w[0].score() <= w[1].score()));
+    assert!(find_units(power, WATT_UNIT, Some(UnitSystem::Si)).iter().all(|m| m.name.is_none()));
+
+    let volume = find_units(2.0 * f64::UK_GALLON, UK_GALLON_UNIT, Some(UnitSystem::Imperial));
+    assert_eq!(volume[0].name, Some(Name::UkGallon));
+    assert!(volume.iter().all(|m| m.name.unwrap().info().systems.contains(&UnitSystem::Imperial)));
+    assert!(find_units(1.0, GRAVITATIONAL_CONSTANT_UNIT, None).iter().all(|m| m.name.is_none()));
+
+    #[cfg(mks_checked)]
+    {
+        let pressure = MksVal::new(1.0, f64::BAR, BAR_UNIT);
+        assert_eq!(pressure.best_unit(None).unwrap().symbol, "bar");
+        assert_eq!(MksVal::new(1.0, 1.0, GRAVITATIONAL_CONSTANT_UNIT).best_unit(Some(UnitSystem::Cgs)), None);
+    }
+}
+
+#[test]
+fn codata_editions() {
+    use codata::*;
+    use list::Name;
+    assert_eq!(Edition::ALL.map(|e| e.year()), [2006, 2014, 2018, 2022]);
+    assert_eq!(Edition::from_year(2018), Some(Edition::Codata2018));
+    assert_eq!(Edition::from_year(2010), None);
+    assert_eq!(Edition::LATEST, Codata2022::EDITION);
+
+    for edition in Edition::ALL {
+        let value = |name| edition.value(name).unwrap();
+        let rel = |a: f64, b: f64| ((a - b) / b).abs();
+        assert_eq!(value(Name::ElectronVolt), value(Name::ElectronCharge));
+        assert_eq!(value(Name::SpeedOfLight), f64::SPEED_OF_LIGHT);
+        assert!(rel(value(Name::PlancksConstantHBar), value(Name::PlancksConstantH) / (2.0 * std::f64::consts::PI)) < 1e-8);
+        let c = value(Name::SpeedOfLight);
+        assert!(rel(value(Name::VacuumPermittivity), 1.0 / (value(Name::VacuumPermeability) * c * c)) < 1e-9);
+        assert!(rel(value(Name::BohrMagneton) / value(Name::NuclearMagneton),
+            value(Name::MassProton) / value(Name::MassElectron)) < 1e-8);
+        assert!(edition.value(Name::Foot).is_none());
+    }
+
+    // `Mks` follows the CODATA 2006 listing, version 0.1 had 6.673e-11 from GSL, see CHANGELOG.md
+    assert_eq!(Codata2006::GRAVITATIONAL_CONSTANT, f64::GRAVITATIONAL_CONSTANT);
+    assert_eq!(f64::GRAVITATIONAL_CONSTANT, 6.67428e-11);
+    for name in Name::iter().filter(|name| name.info().source == list::SOURCE_CODATA) {
+        let listed = Edition::Codata2006.value(name).unwrap_or(name.info().factor);
+        assert_eq!(listed, name.info().factor, "{:?}", name);
+    }
+    assert_eq!(f64::VACUUM_PERMEABILITY, 4.0e-7 * std::f64::consts::PI);
+    assert_eq!(f64::VACUUM_PERMITTIVITY, Codata2006::VACUUM_PERMITTIVITY);
+    // magnitude, as in GSL
+    assert_eq!(f64::ELECTRON_MAGNETIC_MOMENT, 9.28476377e-24);
+    assert!(Edition::ALL.iter().all(|e| e.value(Name::ElectronMagneticMoment).unwrap() > 0.0));
+    // 2006 uncertainties are read from the listing too
+    assert_eq!(Edition::Codata2006.uncertainty(Name::GravitationalConstant), Some(6.7e-15));
+    assert_eq!(Edition::Codata2006.uncertainty(Name::ElectronMagneticMoment), Some(2.3e-31));
+    assert_eq!(Edition::Codata2006.uncertainty(Name::VacuumPermeability), Some(0.0));
+    assert_eq!(list::SOURCE_CODATA, "CODATA 2006");
+    assert_eq!(Name::Foot.info().source, list::SOURCE_GSL);
+    assert_eq!(Codata2018::ELECTRON_CHARGE, 1.602176634e-19);
+    assert_eq!(Codata2022::MASS_ELECTRON, Edition::Codata2022.val(Name::MassElectron).unwrap().val);
+}
+
+#[test]
+fn uncertain_values() {
+    use codata::Edition;
+    use list::Name;
+    use uncertain::Uncertain;
+    let rel = |a: f64, b: f64| ((a - b) / b).abs();
+
+    for edition in Edition::ALL {
+        let u = |name| edition.uncertain(name).unwrap();
+        assert!(u(Name::SpeedOfLight).is_exact());
+        assert!(!u(Name::GravitationalConstant).is_exact());
+        assert!(u(Name::MassElectron).relative_sigma() < 1e-6);
+        assert!(edition.uncertain(Name::Foot).is_none());
+    }
+    assert!(Edition::Codata2018.uncertain(Name::ElectronCharge).unwrap().is_exact());
+    assert!(!Edition::Codata2014.uncertain(Name::ElectronCharge).unwrap().is_exact());
+
+    let a = Uncertain::new(MksVal::new(3.0, 1.0, METER_UNIT), 0.3);
+    let b = Uncertain::new(MksVal::new(4.0, 1.0, METER_UNIT), 0.4);
+    assert!(rel((a + b).sigma, 0.5) < 1e-12);
+    assert!(rel((a - b).sigma, 0.5) < 1e-12);
+    assert_eq!((a - b).value.val, -1.0);
+    assert!(rel((a * b).relative_sigma(), 0.1 * 2.0_f64.sqrt()) < 1e-12);
+    assert!(rel((a / b).relative_sigma(), 0.1 * 2.0_f64.sqrt()) < 1e-12);
+    assert!(rel(a.pow(2).relative_sigma(), 0.2) < 1e-12);
+    assert!(rel(a.pow(-1).relative_sigma(), 0.1) < 1e-12);
+    let c = Uncertain::new(MksVal::new_scalar(8.0), 0.8);
+    assert!(rel(c.sqrt().relative_sigma(), 0.05) < 1e-12);
+    assert!(rel(c.cbrt().relative_sigma(), 0.1 / 3.0) < 1e-12);
+    assert!(rel(c.cbrt().value.val, 2.0) < 1e-12);
+    let doubled = MksVal::new_scalar(2.0) * a;
+    assert_eq!((doubled.value.val, doubled.sigma), (6.0, 0.6));
+    assert_eq!((-a).sigma, 0.3);
+    assert!(Uncertain::from(MksVal::new_scalar(1.0)).is_exact());
+    assert_eq!(Uncertain::new(MksVal::new_scalar(5.0), -0.5).sigma, 0.5);
+
+    assert_eq!(Uncertain::new(MksVal::new_scalar(9.999996), 5.0e-4).to_string(), "1.000000(50)e1");
+
+    #[cfg(mks_checked)]
+    {
+        let g = Edition::Codata2014.uncertain(Name::GravitationalConstant).unwrap();
+        assert_eq!(g.to_string(), "6.67408(31)e-11 m^3 / kg s^2");
+        let k = Edition::Codata2006.uncertain(Name::Boltzmann).unwrap();
+        assert_eq!(format!("{:#}", k), "1.3806504(24)e-23 J / K");
+        assert!(a.try_add(Uncertain::exact(MksVal::new_scalar(1.0))).is_err());
+        let hbar_c = Edition::Codata2022.uncertain(Name::PlancksConstantHBar).unwrap()
+            * Edition::Codata2022.uncertain(Name::SpeedOfLight).unwrap();
+        assert!(hbar_c.is_exact());
+        assert!(hbar_c.value.unit == JOULE_UNIT * METER_UNIT);
+    }
+}
+
+#[test]
+fn constants_audit() {
+    use audit::*;
+    use codata::Edition;
+    use list::Name;
+    assert!(audit().len() >= 10);
+    for check in audit().into_iter().chain(Edition::ALL.into_iter().flat_map(audit_edition)) {
+        assert!(check.passes(1e-6), "{}", check);
+    }
+    let exact = audit_edition(Edition::Codata2018);
+    let hbar = exact.iter().find(|check| check.name == "reduced Planck constant").unwrap();
+    assert!(hbar.discrepancy() < 1e-9);
+    // only relations of Planck units, listed with few digits, have own tolerance
+    assert!(exact.iter().all(|check| (check.tolerance > 0.0) == check.name.starts_with("Planck ")));
+
+    // Planck relations are as tolerant as uncertainties of the edition
+    let planck_length = |edition| audit_edition(edition).into_iter()
+        .find(|check| check.name == "Planck length").unwrap().tolerance;
+    assert!((planck_length(Edition::Codata2006) / (2.0 * 8.1e-40 / 1.616252e-35) - 1.0).abs() < 1e-12);
+    assert!(planck_length(Edition::Codata2022) < planck_length(Edition::Codata2006));
+
+    // `Mks` constants with CODATA 2006 uncertainties, value scaled by `scale`
+    let mks = |name: Name, scale: f64| {
+        let sigma = Edition::Codata2006.uncertainty(name).unwrap();
+        uncertain::Uncertain::new(MksVal::new(scale, name.info().factor, name.info().unit), sigma)
+    };
+    let faraday = audit_with(|name| mks(name, if name == Name::Faraday { 1.0 + 2e-6 } else { 1.0 }));
+    let failed: Vec<_> = faraday.iter().filter(|check| !check.passes(1e-6)).collect();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].relation, "F = e NA");
+
+    let wrong_mass = audit_with(|name| mks(if name == Name::MassProton { Name::MassNeutron } else { name }, 1.0));
+    let failed: Vec<_> = wrong_mass.iter().filter(|check| !check.passes(1e-6)).collect();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].name, "nuclear magneton");
+    assert!(failed[0].unit_mismatch().is_none());
+    assert!(failed[0].discrepancy() > 1e-4);
+
+    #[cfg(mks_checked)]
+    {
+        let swapped = audit_with(|name| match name {
+            Name::VacuumPermeability => MksVal::new(1.0, f64::VACUUM_PERMITTIVITY, VACUUM_PERMEABILITY_UNIT).into(),
+            _ => mks(name, 1.0),
+        });
+        let failed: Vec<_> = swapped.iter().filter(|check| !check.passes(1e-6)).collect();
+        assert_eq!(failed.len(), 1);
+        assert!(failed[0].unit_mismatch().is_none());
+        assert!(failed[0].to_string().starts_with("vacuum permeability and permittivity, μ₀ ε₀ c² = 1: relative discrepancy"));
+
+        // G of wrong dimension is reported, not a panic in square root
+        let wrong_g = audit_with(|name| mks(if name == Name::GravitationalConstant { Name::Boltzmann } else { name }, 1.0));
+        let failed: Vec<_> = wrong_g.iter().filter(|check| !check.passes(1e-6)).map(|check| check.name).collect();
+        assert_eq!(failed, ["Planck length", "Planck mass"]);
+        assert!(wrong_g.iter().filter(|check| !check.passes(1e-6)).all(|check| check.unit_mismatch().is_some()));
+    }
+}
+
+#[test]
+fn fundamental_constants() {
+    use list::{by_name, Category, Name};
+    assert!(FINE_STRUCTURE_UNIT == SCALAR_UNIT);
+    assert!(AVOGADRO_UNIT == SCALAR_UNIT / MOLE_UNIT);
+    assert!(JOSEPHSON_UNIT == HERTZ_UNIT * ELECTRON_CHARGE_UNIT / JOULE_UNIT);
+    assert!(VON_KLITZING_UNIT * CONDUCTANCE_QUANTUM_UNIT == SCALAR_UNIT);
+    assert!(MAGNETIC_FLUX_QUANTUM_UNIT == GAUSS_UNIT * AREA_UNIT);
+    assert!(HARTREE_ENERGY_UNIT == JOULE_UNIT);
+    assert!(WIEN_DISPLACEMENT_UNIT == METER_UNIT * KELVIN_UNIT);
+    assert!(PLANCK_TIME_UNIT == TIME_UNIT);
+    assert!(MASS_DEUTERON_UNIT == KILOGRAM_UNIT && MASS_ALPHA_UNIT == KILOGRAM_UNIT);
+    assert!(COMPTON_WAVELENGTH_UNIT == DISTANCE_UNIT && CLASSICAL_ELECTRON_RADIUS_UNIT == DISTANCE_UNIT);
+
+    assert_eq!(f64::AVOGADRO, 6.02214179e23);
+    assert!((1.0 / f64::FINE_STRUCTURE - 137.036).abs() < 1e-3);
+    assert!((f64::PLANCK_LENGTH / f64::PLANCK_TIME / f64::SPEED_OF_LIGHT - 1.0).abs() < 1e-5);
+    assert!((f64::MASS_ALPHA / f64::MASS_DEUTERON - 1.987).abs() < 1e-3);
+    assert_eq!(MksVal::HARTREE_ENERGY.val, f64::HARTREE_ENERGY);
+    assert_eq!(f32::AVOGADRO, f64::AVOGADRO as f32);
+
+    assert_eq!(by_name("fine structure constant").map(|info| info.name), Some(Name::FineStructure));
+    assert_eq!(by_name("Avogadro number").map(|info| info.name), Some(Name::Avogadro));
+    assert_eq!(Name::PlanckMass.info().category, Category::Constant);
+    assert_eq!(Name::Josephson.info().source, list::SOURCE_CODATA);
+    for name in [Name::Avogadro, Name::FineStructure, Name::MassAlpha, Name::PlanckTime] {
+        for edition in codata::Edition::ALL {
+            assert!(edition.uncertain(name).is_some());
+        }
+    }
+}
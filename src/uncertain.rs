@@ -0,0 +1,237 @@
+//! Values with standard uncertainty
+//!
+//! (c) 2023 Igor Lesik
+//! MIT license
+//!
+//! [`Uncertain`] is [`MksVal`] with standard uncertainty `σ`, zero for exact values.
+//! Operations propagate uncertainty to first order, assuming the operands are uncorrelated:
+//! use [`Uncertain::pow`] rather than `x * x`, which treats the two factors as independent.
+//!
+//! # Example
+//!
+//! ```
+//! use rustamath_mks::*;
+//! use rustamath_mks::codata::Edition;
+//! use rustamath_mks::list::Name;
+//! # #[cfg(mks_checked)] {
+//! let g = Edition::Codata2018.uncertain(Name::GravitationalConstant).unwrap();
+//! let earth_mass = uncertain::Uncertain::new(MksVal::new(5.9722e24, 1.0, KILOGRAM_UNIT), 6.0e20);
+//! let gm = g * earth_mass;
+//! assert!(gm.value.unit == GRAVITATIONAL_CONSTANT_UNIT * KILOGRAM_UNIT);
+//! assert!((gm.relative_sigma() - g.relative_sigma().hypot(earth_mass.relative_sigma())).abs() < 1e-12);
+//! # }
+//! ```
+
+use std::fmt;
+use std::ops;
+use super::{MksVal, UnitError};
+
+/// Value with unit and standard uncertainty
+#[derive(Debug, Copy, Clone)]
+pub struct Uncertain {
+    /// Central value
+    pub value: MksVal,
+    /// Standard uncertainty in MKS units of the value
+    pub sigma: f64,
+}
+
+impl Uncertain {
+    /// Value with standard uncertainty `sigma` in MKS units of the value
+    pub fn new(value: MksVal, sigma: f64) -> Self {
+        Uncertain { value, sigma: sigma.abs() }
+    }
+
+    /// Exact value, like defined constant
+    pub fn exact(value: MksVal) -> Self {
+        Uncertain { value, sigma: 0.0 }
+    }
+
+    /// Is uncertainty zero
+    pub fn is_exact(&self) -> bool {
+        self.sigma == 0.0
+    }
+
+    /// Relative standard uncertainty `σ / |value|`
+    pub fn relative_sigma(&self) -> f64 {
+        self.sigma / self.value.val.abs()
+    }
+
+    /// Uncertainty of `f(x)` with derivative `df` at the value
+    fn derived(&self, value: MksVal, df: f64) -> Self {
+        Uncertain { value, sigma: (df * self.sigma).abs() }
+    }
+
+    /// Raise to integer power, `σ = |n x^(n-1)| σx`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::uncertain::Uncertain;
+    /// # #[cfg(mks_checked)] {
+    /// let side = Uncertain::new(MksVal::new(2.0, 1.0, METER_UNIT), 0.01);
+    /// let volume = side.pow(3);
+    /// assert!(volume.value.unit == METER_UNIT * METER_UNIT * METER_UNIT);
+    /// assert!((volume.sigma - 0.12).abs() < 1e-12);
+    /// # }
+    /// ```
+    pub fn pow(&self, n: i8) -> Self {
+        self.powf(n as f64)
+    }
+
+    /// Raise to real power, `σ = |x p^(x-1)| σp`
+    ///
+    /// The unit mismatch policy applies as for [`MksVal::powf`].
+    pub fn powf(&self, x: f64) -> Self {
+        self.derived(self.value.powf(x), x * self.value.val.powf(x - 1.0))
+    }
+
+    /// Square root, relative uncertainty is halved
+    pub fn sqrt(&self) -> Self {
+        self.powf(0.5)
+    }
+
+    /// Cubic root, relative uncertainty is divided by 3
+    pub fn cbrt(&self) -> Self {
+        self.powf(1.0 / 3.0)
+    }
+
+    /// Add, fail if units do not match
+    pub fn try_add(&self, rhs: Self) -> Result<Self, UnitError> {
+        Ok(Uncertain { value: self.value.try_add(rhs.value)?, sigma: self.sigma.hypot(rhs.sigma) })
+    }
+
+    /// Subtract, fail if units do not match
+    pub fn try_sub(&self, rhs: Self) -> Result<Self, UnitError> {
+        Ok(Uncertain { value: self.value.try_sub(rhs.value)?, sigma: self.sigma.hypot(rhs.sigma) })
+    }
+}
+
+impl From<MksVal> for Uncertain {
+    fn from(value: MksVal) -> Self {
+        Uncertain::exact(value)
+    }
+}
+
+impl ops::Add for Uncertain {
+    type Output = Self;
+
+    /// Add, `σ = √(σa² + σb²)`, the unit mismatch policy applies
+    /// if units do not match, see [`MismatchPolicy`](super::MismatchPolicy).
+    fn add(self, rhs: Self) -> Self {
+        Uncertain { value: self.value + rhs.value, sigma: self.sigma.hypot(rhs.sigma) }
+    }
+}
+
+impl ops::Sub for Uncertain {
+    type Output = Self;
+
+    /// Subtract, `σ = √(σa² + σb²)`, the unit mismatch policy applies
+    /// if units do not match, see [`MismatchPolicy`](super::MismatchPolicy).
+    fn sub(self, rhs: Self) -> Self {
+        Uncertain { value: self.value - rhs.value, sigma: self.sigma.hypot(rhs.sigma) }
+    }
+}
+
+impl ops::Neg for Uncertain {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Uncertain { value: MksVal::new_scalar(-1.0) * self.value, sigma: self.sigma }
+    }
+}
+
+impl ops::Mul for Uncertain {
+    type Output = Self;
+
+    /// Multiply, `σ = √((b σa)² + (a σb)²)`
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self.value.val, rhs.value.val);
+        Uncertain { value: self.value * rhs.value, sigma: (b * self.sigma).hypot(a * rhs.sigma) }
+    }
+}
+
+impl ops::Div for Uncertain {
+    type Output = Self;
+
+    /// Divide, `σ = √((σa / b)² + (a σb / b²)²)`
+    fn div(self, rhs: Self) -> Self {
+        let (a, b) = (self.value.val, rhs.value.val);
+        Uncertain { value: self.value / rhs.value, sigma: (self.sigma / b).hypot(a * rhs.sigma / (b * b)) }
+    }
+}
+
+impl ops::Mul<MksVal> for Uncertain {
+    type Output = Self;
+
+    /// Multiply by exact value
+    fn mul(self, rhs: MksVal) -> Self {
+        self * Uncertain::exact(rhs)
+    }
+}
+
+impl ops::Div<MksVal> for Uncertain {
+    type Output = Self;
+
+    /// Divide by exact value
+    fn div(self, rhs: MksVal) -> Self {
+        self / Uncertain::exact(rhs)
+    }
+}
+
+impl ops::Mul<Uncertain> for MksVal {
+    type Output = Uncertain;
+
+    /// Multiply exact value
+    fn mul(self, rhs: Uncertain) -> Uncertain {
+        Uncertain::exact(self) * rhs
+    }
+}
+
+impl ops::Div<Uncertain> for MksVal {
+    type Output = Uncertain;
+
+    /// Divide exact value
+    fn div(self, rhs: Uncertain) -> Uncertain {
+        Uncertain::exact(self) / rhs
+    }
+}
+
+impl fmt::Display for Uncertain {
+    /// Print in concise notation with two digits of uncertainty in parentheses, like `6.67430(15)e-11`,
+    /// followed by unit; exact values are printed in scientific notation,
+    /// alternate form `{:#}` uses names of SI derived units.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// use rustamath_mks::uncertain::Uncertain;
+    /// # #[cfg(mks_checked)] {
+    /// let len = Uncertain::new(MksVal::new(1.23456, 1.0, METER_UNIT), 0.00012);
+    /// assert_eq!(len.to_string(), "1.23456(12)e0 m");
+    /// let energy = Uncertain::exact(MksVal::new(2.0, 1.0, JOULE_UNIT));
+    /// assert_eq!(format!("{:#}", energy), "2e0 J");
+    /// # }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let val = self.value.val;
+        if self.sigma == 0.0 || !self.sigma.is_finite() || !val.is_finite() {
+            write!(f, "{:e}", val)?;
+        }
+        else {
+            // last printed digit is the second digit of uncertainty
+            let last = self.sigma.log10().floor() as i32 - 1;
+            let scale = 10_f64.powi(last);
+            let rounded = (val / scale).round() * scale;
+            let exp = if rounded == 0.0 { last } else { rounded.abs().log10().floor() as i32 };
+            let decimals = (exp - last).max(0) as usize;
+            let digits = (self.sigma / 10_f64.powi(exp - decimals as i32)).round();
+            write!(f, "{:.*}({})e{}", decimals, rounded / 10_f64.powi(exp), digits, exp)?;
+        }
+        let unit = self.value.unit_or_scalar();
+        let unit = if f.alternate() { unit.as_named_string() } else { unit.as_string() };
+        if !unit.is_empty() { write!(f, " {}", unit)?; }
+        Ok(())
+    }
+}
@@ -5,7 +5,11 @@
 //!
 
 
-use super::MksUnit;
+use super::{list, Mks, MksNum, MksUnit, UnitError, MismatchPolicy, MISMATCH_POLICY};
+#[cfg(mks_checked)]
+use super::UnitOp;
+#[cfg(not(mks_checked))]
+use super::SCALAR_UNIT;
 
 /// MKS value bundled with its unit of measurement.
 ///
@@ -13,6 +17,7 @@ use super::MksUnit;
 ///
 /// ```
 /// # use rustamath_mks::*;
+/// # #[cfg(mks_checked)] {
 /// // simple pendulum period `T = 2*Pi*sqrt(L/g)`
 /// let pendulum_len = MksVal::new(6.0, f64::FOOT, FOOT_UNIT);
 /// let g = MksVal::new(1.0, f64::GRAV_ACCEL, GRAV_ACCEL_UNIT);
@@ -21,119 +26,468 @@ use super::MksUnit;
 /// let pi_x_2 = MksVal::new_scalar(2.0 * std::f64::consts::PI);
 /// let period = pi_x_2 * pendulum_len_over_accel.sqrt();
 /// assert!(period.unit == TIME_UNIT);
+/// # }
 /// ```
+///
+/// Every MKS constant is also available as a typed constant with its unit:
+///
+/// ```
+/// # use rustamath_mks::*;
+/// # #[cfg(mks_checked)] {
+/// let pendulum_len = MksVal::new_scalar(6.0) * MksVal::FOOT;
+/// let period_squared = pendulum_len / MksVal::GRAV_ACCEL;
+/// assert!(period_squared.unit == TIME_UNIT * TIME_UNIT);
+/// assert!(MksVal::SPEED_OF_LIGHT.unit == VELOCITY_UNIT);
+/// assert_eq!(MksVal::SPEED_OF_LIGHT.val, f64::SPEED_OF_LIGHT);
+/// # }
+/// ```
+///
+/// Value type is `f64` by default, any [`MksNum`] type like `f32` works too:
+///
+/// ```
+/// # use rustamath_mks::*;
+/// # #[cfg(mks_checked)] {
+/// let len: MksVal<f32> = MksVal::new(6.0, f32::FOOT, FOOT_UNIT);
+/// let area = len * len;
+/// assert!(area.unit == AREA_UNIT);
+/// assert_eq!(area.sqrt().val, len.val);
+/// # }
+/// ```
+///
+/// Release builds with `--cfg mks_unchecked` do not store the unit,
+/// `MksVal` becomes a transparent wrapper of its value type and field `unit` is not available,
+/// see [`MismatchPolicy::Unchecked`].
 #[derive(Debug, Copy, Clone)]
-pub struct MksVal {
+#[cfg_attr(not(mks_checked), repr(transparent))]
+pub struct MksVal<T = f64> {
     /// Value
-    pub val: f64,
+    pub val: T,
     /// Unit of measure
+    #[cfg(mks_checked)]
     pub unit: MksUnit
 }
 
-impl MksVal {
+impl<T: MksNum> MksVal<T> {
     /// Create new MKS value
     ///
     /// # Example
     ///
     /// ```
     /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
     /// let half_speed_of_light = MksVal::new(0.5, f64::SPEED_OF_LIGHT, SPEED_OF_LIGHT_UNIT);
     /// assert!(half_speed_of_light.unit != MASS_PROTON_UNIT);
     /// assert!(half_speed_of_light.unit == VELOCITY_UNIT);
+    /// # }
     /// ```
-    pub fn new(val: f64, factor: f64, unit: MksUnit) -> MksVal {
-        MksVal {
+    #[cfg_attr(not(mks_checked), allow(unused_variables))]
+    pub fn new(val: T, factor: T, unit: MksUnit) -> Self {
+        Self {
             val: val * factor,
+            #[cfg(mks_checked)]
             unit
         }
     }
 
     /// Value without any units of measure
-    pub fn new_scalar(val: f64) -> MksVal {
-        MksVal {
+    pub fn new_scalar(val: T) -> Self {
+        Self {
             val,
-            unit: MksUnit {m: 0, k: 0, s: 0, a: 0}
+            #[cfg(mks_checked)]
+            unit: MksUnit {m: 0, k: 0, s: 0, a: 0, kel: 0, mol: 0, cd: 0, den: 1}
+        }
+    }
+
+    /// Unit of the value
+    #[cfg(mks_checked)]
+    pub(crate) fn unit_or_scalar(&self) -> MksUnit {
+        self.unit
+    }
+
+    /// Unit-less, unchecked builds do not store units
+    #[cfg(not(mks_checked))]
+    pub(crate) fn unit_or_scalar(&self) -> MksUnit {
+        SCALAR_UNIT
+    }
+
+    /// Value expressed in named unit from [`list::UNITS`], fail if dimensions do not match
+    ///
+    /// Unchecked builds do not store units and never fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let pressure: MksVal = "30 psi".parse().unwrap();
+    /// assert!((pressure.in_unit(list::Name::Bar).unwrap() - 2.068).abs() < 1e-3);
+    /// assert!(pressure.in_unit(list::Name::Joule).is_err());
+    /// # }
+    /// ```
+    #[cfg_attr(not(mks_checked), allow(unused_variables))]
+    pub fn in_unit(&self, name: list::Name) -> Result<T, UnitError> {
+        let (_, unit, factor, _) = list::record(name);
+        #[cfg(mks_checked)]
+        if self.unit != unit {
+            return Err(UnitError::binary(UnitOp::Convert, self.unit, unit));
         }
+        Ok(self.val / T::from_f64(factor))
     }
 
     /// Find square root value and adjust units
+    ///
+    /// Unit powers must be even, otherwise the unit mismatch policy applies,
+    /// see [`MksVal::try_sqrt`] and [`MismatchPolicy`].
     pub fn sqrt(&self) -> Self {
-        Self {
-            val: self.val.sqrt(),
-            unit: MksUnit {m: self.unit.m/2, k: self.unit.k/2, s: self.unit.s/2, a: self.unit.a/2}
-        }
+        self.root(2)
     }
 
     /// Find cubic root value and adjust units
+    ///
+    /// Unit powers must be multiples of 3, otherwise the unit mismatch policy applies,
+    /// see [`MksVal::try_cbrt`] and [`MismatchPolicy`].
     pub fn cbrt(&self) -> Self {
-        Self {
-            val: self.val.cbrt(),
-            unit: MksUnit {m: self.unit.m/3, k: self.unit.k/3, s: self.unit.s/3, a: self.unit.a/3}
+        self.root(3)
+    }
+
+    /// Find integer root value and adjust units
+    ///
+    /// Unit powers must be multiples of `n`, otherwise the unit mismatch policy applies,
+    /// see [`MksVal::try_root`] and [`MismatchPolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let hypervolume = MksVal::new(16.0, f64::METER, AREA_UNIT * AREA_UNIT);
+    /// let side = hypervolume.root(4);
+    /// assert!(side.unit == METER_UNIT);
+    /// assert_eq!(side.val, 2.0);
+    /// # }
+    /// ```
+    pub fn root(&self, n: i8) -> Self {
+        self.try_root(n).unwrap_or_else(mismatch)
+    }
+
+    /// Raise to real power and adjust units
+    ///
+    /// Unit powers multiplied by `x` must be integers, otherwise the unit mismatch policy applies,
+    /// see [`MksVal::try_powf`] and [`MismatchPolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let area = MksVal::new(8.0, f64::METER, AREA_UNIT * AREA_UNIT * AREA_UNIT);
+    /// assert!(area.powf(1.0 / 3.0).unit == AREA_UNIT);
+    /// assert!(area.powf(0.5).unit == AREA_UNIT * METER_UNIT);
+    /// # }
+    /// ```
+    pub fn powf(&self, x: f64) -> Self {
+        self.try_powf(x).unwrap_or_else(mismatch)
+    }
+
+    /// Find integer root value, fail if unit powers are not multiples of `n`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let accel = MksVal::new(1.0, f64::GRAV_ACCEL, GRAV_ACCEL_UNIT);
+    /// let err = accel.try_root(2).unwrap_err();
+    /// assert_eq!(&err.to_string(), "cannot take square root of [m / s^2]");
+    /// assert!(accel.pow(5).try_root(5).unwrap().unit == GRAV_ACCEL_UNIT);
+    /// # }
+    /// ```
+    pub fn try_root(&self, n: i8) -> Result<Self, UnitError> {
+        #[cfg(mks_checked)]
+        let op = match n { 2 => UnitOp::Sqrt, 3 => UnitOp::Cbrt, _ => UnitOp::Root(n) };
+        Ok(Self {
+            val: root_val(self.val, n),
+            #[cfg(mks_checked)]
+            unit: self.unit.checked_root(n)
+                .ok_or_else(|| UnitError::unary(op, self.unit))?
+        })
+    }
+
+    /// Raise to real power, fail if unit powers multiplied by `x` are not integers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let area = MksVal::new(4.0, f64::METER, AREA_UNIT);
+    /// assert!(area.try_powf(1.5).unwrap().unit == LITER_UNIT);
+    /// assert!(area.try_powf(0.25).is_err());
+    /// # }
+    /// ```
+    pub fn try_powf(&self, x: f64) -> Result<Self, UnitError> {
+        Ok(Self {
+            val: self.val.powf(T::from_f64(x)),
+            #[cfg(mks_checked)]
+            unit: self.unit.checked_powf(x)
+                .ok_or_else(|| UnitError::unary(UnitOp::Powf(x), self.unit))?
+        })
+    }
+
+    /// Add 2 MKS values, fail if units do not match
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let len = MksVal::new(6.0, f64::FOOT, FOOT_UNIT);
+    /// assert!(len.try_add(len).is_ok());
+    /// assert!(len.try_add(MksVal::new(1.0, f64::KILOGRAM, KILOGRAM_UNIT)).is_err());
+    /// # }
+    /// ```
+    pub fn try_add(&self, rhs: Self) -> Result<Self, UnitError> {
+        #[cfg(mks_checked)]
+        if self.unit != rhs.unit {
+            return Err(UnitError::binary(UnitOp::Add, self.unit, rhs.unit));
+        }
+        Ok(Self {
+            #[cfg(mks_checked)]
+            unit: self.unit,
+            val: self.val + rhs.val
+        })
+    }
+
+    /// Subtract 2 MKS values, fail if units do not match
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let len = MksVal::new(6.0, f64::FOOT, FOOT_UNIT);
+    /// let err = len.try_sub(MksVal::new(1.0, f64::SECOND, SECOND_UNIT)).unwrap_err();
+    /// assert_eq!(&err.to_string(), "cannot subtract [m] and [s]");
+    /// # }
+    /// ```
+    pub fn try_sub(&self, rhs: Self) -> Result<Self, UnitError> {
+        #[cfg(mks_checked)]
+        if self.unit != rhs.unit {
+            return Err(UnitError::binary(UnitOp::Sub, self.unit, rhs.unit));
         }
+        Ok(Self {
+            #[cfg(mks_checked)]
+            unit: self.unit,
+            val: self.val - rhs.val
+        })
+    }
+
+    /// Find square root value, fail if unit powers are not even
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let area = MksVal::new(4.0, f64::METER, AREA_UNIT);
+    /// assert!(area.try_sqrt().unwrap().unit == METER_UNIT);
+    /// assert!(MksVal::new(4.0, f64::METER, METER_UNIT).try_sqrt().is_err());
+    /// # }
+    /// ```
+    pub fn try_sqrt(&self) -> Result<Self, UnitError> {
+        self.try_root(2)
+    }
+
+    /// Find cubic root value, fail if unit powers are not multiples of 3
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let volume = MksVal::new(8.0, f64::LITER, LITER_UNIT);
+    /// assert!(volume.try_cbrt().unwrap().unit == METER_UNIT);
+    /// assert!(MksVal::new(8.0, f64::METER, AREA_UNIT).try_cbrt().is_err());
+    /// # }
+    /// ```
+    pub fn try_cbrt(&self) -> Result<Self, UnitError> {
+        self.try_root(3)
+    }
+
+    /// Raise to integer power, fail if unit powers overflow
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let len = MksVal::new(2.0, f64::METER, METER_UNIT);
+    /// assert!(len.try_pow(3).unwrap().unit == LITER_UNIT);
+    /// assert!(len.try_pow(100).unwrap().try_pow(100).is_err());
+    /// # }
+    /// ```
+    pub fn try_pow(&self, n: i8) -> Result<Self, UnitError> {
+        Ok(Self {
+            val: self.val.powi(n.into()),
+            #[cfg(mks_checked)]
+            unit: self.unit.checked_pow(n)
+                .ok_or_else(|| UnitError::unary(UnitOp::Pow(n), self.unit))?
+        })
     }
 
     /// Raise to integer power and adjust units
     pub fn pow(&self, n: i8) -> Self {
         Self {
             val: self.val.powi(n.into()),
-            unit: MksUnit {
-                m: self.unit.m * n ,
-                k: self.unit.k * n,
-                s: self.unit.s * n,
-                a: self.unit.a * n
-            }
+            #[cfg(mks_checked)]
+            unit: self.unit.checked_pow(n).expect("unit power overflow")
+        }
+    }
+
+    /// Raise to rational power `num/den` and adjust units,
+    /// unit powers become fractions if needed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// // noise spectral density of 4 nV/sqrt(Hz)
+    /// let volt = JOULE_UNIT / (AMPERE_UNIT * SECOND_UNIT);
+    /// let hertz = SCALAR_UNIT / SECOND_UNIT;
+    /// let noise = MksVal::new(16.0e-18, 1.0, volt * volt / hertz).pow_ratio(1, 2);
+    /// assert!(noise.unit == volt * SECOND_UNIT.checked_pow_ratio(1, 2).unwrap());
+    /// assert_eq!(noise.val, 4.0e-9);
+    /// # }
+    /// ```
+    pub fn pow_ratio(&self, num: i8, den: i8) -> Self {
+        self.try_pow_ratio(num, den).unwrap_or_else(mismatch)
+    }
+
+    /// Raise to rational power `num/den`, fail if `den` is 0 or unit powers overflow
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
+    /// let len = MksVal::new(4.0, f64::METER, METER_UNIT);
+    /// assert!(len.try_pow_ratio(1, 2).unwrap().unit.to_string() == "[m^(1/2)]");
+    /// assert!(len.try_pow_ratio(1, 0).is_err());
+    /// # }
+    /// ```
+    pub fn try_pow_ratio(&self, num: i8, den: i8) -> Result<Self, UnitError> {
+        #[cfg(mks_checked)]
+        let unit = self.unit.checked_pow_ratio(num, den)
+            .ok_or_else(|| UnitError::unary(UnitOp::PowRatio(num, den), self.unit))?;
+        let val = match (num, den) {
+            (1, 2) => self.val.sqrt(),
+            (1, 3) => self.val.cbrt(),
+            _ => self.val.powf(T::from_f64(f64::from(num) / f64::from(den)))
+        };
+        Ok(Self {
+            val,
+            #[cfg(mks_checked)]
+            unit
+        })
+    }
+}
+
+/// Expand rows of `mks_table!` into typed constants of [`MksVal`].
+macro_rules! mks_values {
+    ($($name:ident, $cnst:ident, $unit:ident, [$($pow:expr),*], $val:expr, $descr:literal;)*) => {
+        impl MksVal<f64> {
+            $(
+                #[doc = concat!($descr, ", `f64::", stringify!($cnst), "` in `", stringify!($unit), "`")]
+                pub const $cnst: MksVal = MksVal {
+                    val: <f64 as Mks>::$cnst,
+                    #[cfg(mks_checked)]
+                    unit: super::$unit
+                };
+            )*
         }
+    };
+}
+
+mks_table!(mks_values);
+
+/// Real `n`-th root of a number
+fn root_val<T: MksNum>(val: T, n: i8) -> T {
+    let inv_n = T::from_f64(1.0 / f64::from(n));
+    match n {
+        2 => val.sqrt(),
+        3 => val.cbrt(),
+        _ if n % 2 != 0 && val < T::from_f64(0.0) => -(-val).powf(inv_n),
+        _ => val.powf(inv_n)
+    }
+}
+
+/// Result of plain operation on values with mismatched units, see [`MismatchPolicy`].
+pub(crate) fn mismatch<T: MksNum>(err: UnitError) -> MksVal<T> {
+    match MISMATCH_POLICY {
+        MismatchPolicy::Nan => MksVal::new(T::from_f64(f64::NAN), T::from_f64(1.0), err.lhs),
+        _ => panic!("{}", err)
     }
 }
 
-impl std::ops::Add for MksVal {
+impl<T: MksNum> std::ops::Add for MksVal<T> {
     type Output = Self;
 
     /// Add 2 MKS values respecting their units
     ///
+    /// Mismatched units panic unless other [`MismatchPolicy`] is selected
+    /// for release builds.
+    ///
     /// # Example
     ///
-    /// ```should_panic
+    /// ```
     /// use rustamath_mks::*;
     /// let half_speed_of_light = MksVal::new(0.5, f64::SPEED_OF_LIGHT, SPEED_OF_LIGHT_UNIT);
     /// let speed_of_light = half_speed_of_light + half_speed_of_light;
-    /// // we are going to panic on next line
-    /// let speed_of_light = half_speed_of_light + MksVal::new(1.0, f64::MASS_PROTON, MASS_PROTON_UNIT);
+    /// assert_eq!(speed_of_light.val, f64::SPEED_OF_LIGHT);
+    /// let proton_mass = MksVal::new(1.0, f64::MASS_PROTON, MASS_PROTON_UNIT);
+    /// let sum = std::panic::catch_unwind(|| half_speed_of_light + proton_mass);
+    /// match MISMATCH_POLICY {
+    ///     MismatchPolicy::Panic => assert!(sum.is_err()),
+    ///     MismatchPolicy::Nan => assert!(sum.unwrap().val.is_nan()),
+    ///     MismatchPolicy::Unchecked => assert!(sum.is_ok()),
+    /// }
     /// ```
     fn add(self, rhs: Self) -> Self {
-        debug_assert!(self.unit == rhs.unit);
-        Self {
-            unit: self.unit,
-            val: self.val + rhs.val
-        }
+        self.try_add(rhs).unwrap_or_else(mismatch)
     }
 }
 
 
-impl std::ops::Sub for MksVal {
+impl<T: MksNum> std::ops::Sub for MksVal<T> {
     type Output = Self;
 
     /// Subtruct 2 MKS values respecting their units
     ///
+    /// Mismatched units panic unless other [`MismatchPolicy`] is selected
+    /// for release builds.
+    ///
     /// # Example
     ///
-    /// ```should_panic
+    /// ```
     /// use rustamath_mks::*;
     /// let half_speed_of_light = MksVal::new(0.5, f64::SPEED_OF_LIGHT, SPEED_OF_LIGHT_UNIT);
     /// let speed_of_light = half_speed_of_light + half_speed_of_light;
-    /// // we are going to panic on next line
-    /// let speed_of_light = half_speed_of_light - MksVal::new(1.0, f64::MASS_PROTON, MASS_PROTON_UNIT);
+    /// assert_eq!(speed_of_light.val, f64::SPEED_OF_LIGHT);
+    /// let proton_mass = MksVal::new(1.0, f64::MASS_PROTON, MASS_PROTON_UNIT);
+    /// let difference = std::panic::catch_unwind(|| half_speed_of_light - proton_mass);
+    /// match MISMATCH_POLICY {
+    ///     MismatchPolicy::Panic => assert!(difference.is_err()),
+    ///     MismatchPolicy::Nan => assert!(difference.unwrap().val.is_nan()),
+    ///     MismatchPolicy::Unchecked => assert!(difference.is_ok()),
+    /// }
     /// ```
     fn sub(self, rhs: Self) -> Self {
-        debug_assert!(self.unit == rhs.unit);
-        Self {
-            unit: self.unit,
-            val: self.val - rhs.val
-        }
+        self.try_sub(rhs).unwrap_or_else(mismatch)
     }
 }
 
-impl std::ops::Mul for MksVal {
+impl<T: MksNum> std::ops::Mul for MksVal<T> {
     type Output = Self;
 
     /// Multiply 2 MKS values calculating result unit
@@ -142,19 +496,22 @@ impl std::ops::Mul for MksVal {
     ///
     /// ```
     /// use rustamath_mks::*;
+    /// # #[cfg(mks_checked)] {
     /// let half_speed_of_light = MksVal::new(0.5, f64::SPEED_OF_LIGHT, SPEED_OF_LIGHT_UNIT);
     /// let distance_after_2_weeks = half_speed_of_light * MksVal::new(2.0, f64::WEEK, WEEK_UNIT);
     /// assert!(distance_after_2_weeks.unit == DISTANCE_UNIT);
+    /// # }
     /// ```
     fn mul(self, rhs: Self) -> Self {
         Self {
+            #[cfg(mks_checked)]
             unit: self.unit * rhs.unit,
             val: self.val * rhs.val
         }
     }
 }
 
-impl std::ops::Div for MksVal {
+impl<T: MksNum> std::ops::Div for MksVal<T> {
     type Output = Self;
 
     /// Divide 2 MKS values calculating result unit
@@ -164,13 +521,16 @@ impl std::ops::Div for MksVal {
     /// ```
     /// use rustamath_mks::*;
     /// # use assert_float_eq::*;
+    /// # #[cfg(mks_checked)] {
     /// let speed_of_light = MksVal::new(1.0, f64::SPEED_OF_LIGHT, SPEED_OF_LIGHT_UNIT);
     /// let traveling_time = MksVal::new(1.0, f64::LIGHT_YEAR, LIGHT_YEAR_UNIT) / speed_of_light;
     /// assert!(traveling_time.unit == TIME_UNIT);
     /// assert_float_relative_eq!(traveling_time.val, f64::DAY * 365.25, 1.0e-4); // https://en.wikipedia.org/wiki/Light-year
+    /// # }
     /// ```
     fn div(self, rhs: Self) -> Self {
         Self {
+            #[cfg(mks_checked)]
             unit: self.unit / rhs.unit,
             val: self.val / rhs.val
         }